@@ -1,10 +1,10 @@
-use std::env;
+use std::{env, path::Path};
 
 fn main() {
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
 
     cbindgen::Builder::new()
-        .with_crate(crate_dir)
+        .with_src(Path::new(&crate_dir).join("src/ffi.rs"))
         .with_language(cbindgen::Language::Cxx)
         .with_pragma_once(true)
         .with_namespace("uvc_control")
@@ -12,108 +12,765 @@ using ErrorCode = int32_t;
 
 using DeviceInfoList = void*;
 
+/// `location_id` is only known on macOS and is 0 elsewhere.
+///
+/// `info` is a list holding only the device that arrived or left and is valid until the callback
+/// returns. Pass it with index 0 to the `uvc_control_info_*` functions, e.g. to tell identical
+/// cameras apart by port or to `uvc_control_info_open` the one that arrived.
+using HotplugCallback = void(*)(void *user_data,
+                                bool arrived,
+                                uint16_t vendor_id,
+                                uint16_t product_id,
+                                uint32_t location_id,
+                                const DeviceInfoList *info);
+
+using HotplugListener = void*;
+
+using Speed = uint8_t;
+
 using Device = void*;
 
+using TransferErrors = uint32_t;
+
+using PowerLineFrequency = uint8_t;
+
+using ButtonCallback = void(*)(void *user_data, uint8_t interface, bool pressed);
+
+using ButtonListener = void*;
+
 constexpr static const ErrorCode ERROR_CODE_SUCCESS = 0;
 
 constexpr static const ErrorCode ERROR_CODE_UNKNOWN = -1;
 
+constexpr static const PowerLineFrequency POWER_LINE_FREQUENCY_DISABLED = 0;
+
+constexpr static const PowerLineFrequency POWER_LINE_FREQUENCY_50HZ = 1;
+
+constexpr static const PowerLineFrequency POWER_LINE_FREQUENCY_60HZ = 2;
+
+constexpr static const PowerLineFrequency POWER_LINE_FREQUENCY_AUTO = 3;
+
+constexpr static const TransferErrors TRANSFER_ERROR_CANCELLED = (1 << 0);
+
+constexpr static const TransferErrors TRANSFER_ERROR_STALL = (1 << 1);
+
+constexpr static const TransferErrors TRANSFER_ERROR_DISCONNECTED = (1 << 2);
+
+constexpr static const TransferErrors TRANSFER_ERROR_FAULT = (1 << 3);
+
+constexpr static const TransferErrors TRANSFER_ERROR_UNKNOWN = (1 << 4);
+
+constexpr static const Speed SPEED_UNKNOWN = 0;
+
+constexpr static const Speed SPEED_LOW = 1;
+
+constexpr static const Speed SPEED_FULL = 2;
+
+constexpr static const Speed SPEED_HIGH = 3;
+
+constexpr static const Speed SPEED_SUPER = 4;
+
+constexpr static const Speed SPEED_SUPER_PLUS = 5;
+
 extern "C" {
 
+/// # Safety
+///
+/// `p_list` must be valid for writes.
 ErrorCode uvc_control_enumerate(DeviceInfoList *p_list);
 
+/// # Safety
+///
+/// `callback` is called with `user_data` from another thread until the listener is dropped, so
+/// `user_data` must stay valid and usable from that thread until then. `p_listener` must be valid
+/// for writes.
+ErrorCode uvc_control_watch(HotplugCallback callback, void *user_data, HotplugListener *p_listener);
+
+/// Stops the watcher thread; the callback is not called after this returns.
+void uvc_control_hotplug_listener_drop(HotplugListener listener);
+
 void uvc_control_info_list_drop(DeviceInfoList list);
 
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid.
 uintptr_t uvc_control_info_list_len(const DeviceInfoList *list);
 
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
 uint16_t uvc_control_info_product_id(const DeviceInfoList *list, uintptr_t index);
 
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
 uint16_t uvc_control_info_vendor_id(const DeviceInfoList *list, uintptr_t index);
 
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `data_ptr` must be null or valid for writes of `data_len`
+/// bytes.
+ErrorCode uvc_control_info_serial_number(const DeviceInfoList *list,
+                                         uintptr_t index,
+                                         char *data_ptr,
+                                         uintptr_t data_len);
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `data_ptr` must be null or valid for writes of `data_len`
+/// bytes.
+ErrorCode uvc_control_info_manufacturer_string(const DeviceInfoList *list,
+                                               uintptr_t index,
+                                               char *data_ptr,
+                                               uintptr_t data_len);
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+uint8_t uvc_control_info_bus_number(const DeviceInfoList *list, uintptr_t index);
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+uint8_t uvc_control_info_device_address(const DeviceInfoList *list, uintptr_t index);
+
+/// Writes up to `data_len` port numbers and stores how many the chain has in `len`.
+///
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `data_ptr` must be null or valid for writes of `data_len`
+/// bytes, and `len` valid for writes.
+ErrorCode uvc_control_info_port_chain(const DeviceInfoList *list,
+                                      uintptr_t index,
+                                      uint8_t *data_ptr,
+                                      uintptr_t data_len,
+                                      uintptr_t *len);
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+Speed uvc_control_info_speed(const DeviceInfoList *list, uintptr_t index);
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+uint16_t uvc_control_info_device_version(const DeviceInfoList *list, uintptr_t index);
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `p_device` must be valid for writes.
 ErrorCode uvc_control_info_open(const DeviceInfoList *list, uintptr_t index, Device *p_device);
 
+/// `retryable` is a mask of `TRANSFER_ERROR_*` flags; a timed out transfer fails as cancelled.
+///
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `p_device` must be valid for writes.
+ErrorCode uvc_control_info_open_with(const DeviceInfoList *list,
+                                     uintptr_t index,
+                                     uint32_t timeout_ms,
+                                     uint32_t retries,
+                                     uint32_t backoff_ms,
+                                     TransferErrors retryable,
+                                     Device *p_device);
+
+/// `retryable` is a mask of `TRANSFER_ERROR_*` flags; a timed out transfer fails as cancelled.
+///
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`, and must not be in use
+/// on another thread during the call.
+ErrorCode uvc_control_device_set_policy(Device *device,
+                                        uint32_t timeout_ms,
+                                        uint32_t retries,
+                                        uint32_t backoff_ms,
+                                        TransferErrors retryable);
+
 void uvc_control_device_drop(Device device);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 ErrorCode uvc_control_device_zoom_abs_caps(const Device *device,
                                            int32_t *min,
                                            int32_t *max,
                                            int32_t *res,
                                            int32_t *def);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 ErrorCode uvc_control_device_zoom_abs(const Device *device, int32_t *cur);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_zoom_abs_set(const Device *device, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 ErrorCode uvc_control_device_zoom_rel_caps(const Device *device,
                                            int32_t *min,
                                            int32_t *max,
                                            int32_t *res,
                                            int32_t *def);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 ErrorCode uvc_control_device_zoom_rel(const Device *device, int32_t *cur);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_zoom_rel_set(const Device *device, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_zoom_rel_speed_caps(const Device *device,
+                                                 int32_t *min,
+                                                 int32_t *max,
+                                                 int32_t *res,
+                                                 int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_zoom_rel_move(const Device *device, int32_t direction, int32_t speed);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 ErrorCode uvc_control_device_pan_abs_caps(const Device *device,
                                           int32_t *min,
                                           int32_t *max,
                                           int32_t *res,
                                           int32_t *def);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 ErrorCode uvc_control_device_pan_abs(const Device *device, int32_t *cur);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_pan_abs_set(const Device *device, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 ErrorCode uvc_control_device_pan_rel_caps(const Device *device,
                                           int32_t *min,
                                           int32_t *max,
                                           int32_t *res,
                                           int32_t *def);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 ErrorCode uvc_control_device_pan_rel(const Device *device, int32_t *cur);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_pan_rel_set(const Device *device, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_pan_rel_speed_caps(const Device *device,
+                                                int32_t *min,
+                                                int32_t *max,
+                                                int32_t *res,
+                                                int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_pan_rel_move(const Device *device, int32_t direction, int32_t speed);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 ErrorCode uvc_control_device_tilt_abs_caps(const Device *device,
                                            int32_t *min,
                                            int32_t *max,
                                            int32_t *res,
                                            int32_t *def);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 ErrorCode uvc_control_device_tilt_abs(const Device *device, int32_t *cur);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_tilt_abs_set(const Device *device, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 ErrorCode uvc_control_device_tilt_rel_caps(const Device *device,
                                            int32_t *min,
                                            int32_t *max,
                                            int32_t *res,
                                            int32_t *def);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 ErrorCode uvc_control_device_tilt_rel(const Device *device, int32_t *cur);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_tilt_rel_set(const Device *device, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_tilt_rel_speed_caps(const Device *device,
+                                                 int32_t *min,
+                                                 int32_t *max,
+                                                 int32_t *res,
+                                                 int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_tilt_rel_move(const Device *device, int32_t direction, int32_t speed);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `pan` and `tilt` must
+/// be valid for writes.
+ErrorCode uvc_control_device_pan_tilt_abs(const Device *device, int32_t *pan, int32_t *tilt);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_pan_tilt_abs_set(const Device *device, int32_t pan, int32_t tilt);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_pan_tilt_rel_set(const Device *device,
+                                              int32_t pan_direction,
+                                              int32_t pan_speed,
+                                              int32_t tilt_direction,
+                                              int32_t tilt_speed);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_focus_abs_caps(const Device *device,
+                                            int32_t *min,
+                                            int32_t *max,
+                                            int32_t *res,
+                                            int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_focus_abs(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_focus_abs_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_focus_rel_speed_caps(const Device *device,
+                                                  int32_t *min,
+                                                  int32_t *max,
+                                                  int32_t *res,
+                                                  int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_focus_rel(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_focus_rel_set(const Device *device, int32_t direction, int32_t speed);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_focus_auto(const Device *device, bool *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_focus_auto_set(const Device *device, bool value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_privacy(const Device *device, bool *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_privacy_set(const Device *device, bool value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_brightness_caps(const Device *device,
+                                             int32_t *min,
+                                             int32_t *max,
+                                             int32_t *res,
+                                             int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_brightness(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_brightness_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_contrast_caps(const Device *device,
+                                           int32_t *min,
+                                           int32_t *max,
+                                           int32_t *res,
+                                           int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_contrast(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_contrast_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_hue_caps(const Device *device,
+                                      int32_t *min,
+                                      int32_t *max,
+                                      int32_t *res,
+                                      int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_hue(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_hue_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_saturation_caps(const Device *device,
+                                             int32_t *min,
+                                             int32_t *max,
+                                             int32_t *res,
+                                             int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_saturation(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_saturation_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_sharpness_caps(const Device *device,
+                                            int32_t *min,
+                                            int32_t *max,
+                                            int32_t *res,
+                                            int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_sharpness(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_sharpness_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_gamma_caps(const Device *device,
+                                        int32_t *min,
+                                        int32_t *max,
+                                        int32_t *res,
+                                        int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_gamma(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_gamma_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_gain_caps(const Device *device,
+                                       int32_t *min,
+                                       int32_t *max,
+                                       int32_t *res,
+                                       int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_gain(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_gain_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_hue_auto(const Device *device, bool *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_hue_auto_set(const Device *device, bool value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_contrast_auto(const Device *device, bool *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_contrast_auto_set(const Device *device, bool value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_power_line_frequency(const Device *device, PowerLineFrequency *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_power_line_frequency_set(const Device *device,
+                                                      PowerLineFrequency value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_backlight_compensation_caps(const Device *device,
+                                                         int32_t *min,
+                                                         int32_t *max,
+                                                         int32_t *res,
+                                                         int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_backlight_compensation(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_backlight_compensation_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_digital_multiplier_caps(const Device *device,
+                                                     int32_t *min,
+                                                     int32_t *max,
+                                                     int32_t *res,
+                                                     int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_digital_multiplier(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_digital_multiplier_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_digital_multiplier_limit_caps(const Device *device,
+                                                           int32_t *min,
+                                                           int32_t *max,
+                                                           int32_t *res,
+                                                           int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_digital_multiplier_limit(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_digital_multiplier_limit_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+ErrorCode uvc_control_device_zoom_effective_caps(const Device *device,
+                                                 int32_t *min,
+                                                 int32_t *max,
+                                                 int32_t *res,
+                                                 int32_t *def);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+ErrorCode uvc_control_device_zoom_effective(const Device *device, int32_t *cur);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+ErrorCode uvc_control_device_zoom_effective_set(const Device *device, int32_t value);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `callback` is called
+/// with `user_data` from another thread until the listener is dropped, so `user_data` must stay
+/// valid and usable from that thread until then. `p_listener` must be valid for writes.
+ErrorCode uvc_control_device_listen_button(const Device *device,
+                                           ButtonCallback callback,
+                                           void *user_data,
+                                           ButtonListener *p_listener);
+
+/// Stops the listener thread; the callback is not called after this returns.
+void uvc_control_button_listener_drop(ButtonListener listener);
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `data_ptr` must be
+/// valid for writes of `data_len` bytes.
 ErrorCode uvc_control_device_unix_get(const Device *device,
                                       uint8_t control_code,
                                       uint8_t unit,
                                       uint8_t *data_ptr,
                                       uintptr_t data_len);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `data_ptr` must be
+/// valid for reads of `data_len` bytes.
 ErrorCode uvc_control_device_unix_set(const Device *device,
                                       uint8_t control_code,
                                       uint8_t unit,
                                       const uint8_t *data_ptr,
                                       uintptr_t data_len);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 ErrorCode uvc_control_device_win_set(const Device *device, int32_t control_code, int32_t value);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `set` must be a
+/// NUL-terminated GUID string and `data_ptr` valid for writes of `data_len` bytes.
 ErrorCode uv_control_device_win_get_xu(const Device *device,
                                        const char *set,
                                        uint32_t id,
                                        uint8_t *data_ptr,
                                        uintptr_t data_len);
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `set` must be a
+/// NUL-terminated GUID string and `data_ptr` valid for reads of `data_len` bytes.
 ErrorCode uvc_control_device_win_set_xu(const Device *device,
                                         const char *set,
                                         uint32_t id,
@@ -0,0 +1,329 @@
+//! Little-endian encoding of UVC control payloads.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+}
+
+impl Kind {
+    pub const fn size(self) -> usize {
+        match self {
+            Kind::U8 | Kind::I8 => 1,
+            Kind::U16 | Kind::I16 => 2,
+            Kind::U32 | Kind::I32 => 4,
+        }
+    }
+
+    pub const fn signed(self) -> bool {
+        matches!(self, Kind::I8 | Kind::I16 | Kind::I32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    pub offset: usize,
+    pub kind: Kind,
+}
+
+impl Field {
+    pub const fn new(offset: usize, kind: Kind) -> Self {
+        Field { offset, kind }
+    }
+
+    /// Reads the field, treating bytes past the end of `data` as zero.
+    pub fn read(self, data: &[u8]) -> i64 {
+        let size = self.kind.size();
+        let mut buf = [0u8; 8];
+        if let Some(src) = data.get(self.offset..) {
+            let n = src.len().min(size);
+            buf[..n].copy_from_slice(&src[..n]);
+        }
+
+        let v = u64::from_le_bytes(buf);
+        if self.kind.signed() {
+            let shift = 64 - size as u32 * 8;
+            ((v << shift) as i64) >> shift
+        } else {
+            v as i64
+        }
+    }
+
+    /// Writes the low bytes of `value` into the field.
+    pub fn write(self, data: &mut [u8], value: i64) {
+        let size = self.kind.size();
+        data[self.offset..self.offset + size].copy_from_slice(&value.to_le_bytes()[..size]);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub len: usize,
+    pub fields: &'static [Field],
+}
+
+impl Layout {
+    pub fn decode(&self, data: &[u8]) -> Vec<i32> {
+        self.fields.iter().map(|f| f.read(data) as i32).collect()
+    }
+
+    /// Encodes one value per field; missing values are sent as zero.
+    pub fn encode(&self, values: &[i32]) -> Vec<u8> {
+        let mut data = vec![0; self.len];
+        for (f, &v) in self.fields.iter().zip(values) {
+            f.write(&mut data, v as i64);
+        }
+        data
+    }
+}
+
+const fn single(kind: Kind) -> Layout {
+    const U8: &[Field] = &[Field::new(0, Kind::U8)];
+    const I8: &[Field] = &[Field::new(0, Kind::I8)];
+    const U16: &[Field] = &[Field::new(0, Kind::U16)];
+    const I16: &[Field] = &[Field::new(0, Kind::I16)];
+    const U32: &[Field] = &[Field::new(0, Kind::U32)];
+    const I32: &[Field] = &[Field::new(0, Kind::I32)];
+
+    Layout {
+        len: kind.size(),
+        fields: match kind {
+            Kind::U8 => U8,
+            Kind::I8 => I8,
+            Kind::U16 => U16,
+            Kind::I16 => I16,
+            Kind::U32 => U32,
+            Kind::I32 => I32,
+        },
+    }
+}
+
+// Camera Terminal
+pub const SCANNING_MODE: Layout = single(Kind::U8);
+pub const AE_MODE: Layout = single(Kind::U8);
+pub const AE_PRIORITY: Layout = single(Kind::U8);
+pub const EXPOSURE_TIME_ABS: Layout = single(Kind::U32);
+pub const EXPOSURE_TIME_REL: Layout = single(Kind::I8);
+pub const FOCUS_ABS: Layout = single(Kind::U16);
+pub const FOCUS_REL: Layout = Layout {
+    len: 2,
+    fields: &[Field::new(0, Kind::I8), Field::new(1, Kind::U8)],
+};
+pub const FOCUS_SIMPLE: Layout = single(Kind::U8);
+pub const FOCUS_AUTO: Layout = single(Kind::U8);
+pub const IRIS_ABS: Layout = single(Kind::U16);
+pub const IRIS_REL: Layout = single(Kind::I8);
+pub const ZOOM_ABS: Layout = single(Kind::U16);
+pub const ZOOM_REL: Layout = Layout {
+    len: 3,
+    fields: &[
+        Field::new(0, Kind::I8),
+        Field::new(1, Kind::U8),
+        Field::new(2, Kind::U8),
+    ],
+};
+pub const PAN_TILT_ABS: Layout = Layout {
+    len: 8,
+    fields: &[Field::new(0, Kind::I32), Field::new(4, Kind::I32)],
+};
+pub const PAN_TILT_REL: Layout = Layout {
+    len: 4,
+    fields: &[
+        Field::new(0, Kind::I8),
+        Field::new(1, Kind::U8),
+        Field::new(2, Kind::I8),
+        Field::new(3, Kind::U8),
+    ],
+};
+pub const ROLL_ABS: Layout = single(Kind::I16);
+pub const ROLL_REL: Layout = Layout {
+    len: 2,
+    fields: &[Field::new(0, Kind::I8), Field::new(1, Kind::U8)],
+};
+pub const PRIVACY: Layout = single(Kind::U8);
+pub const WINDOW: Layout = Layout {
+    len: 12,
+    fields: &[
+        Field::new(0, Kind::U16),
+        Field::new(2, Kind::U16),
+        Field::new(4, Kind::U16),
+        Field::new(6, Kind::U16),
+        Field::new(8, Kind::U16),
+        Field::new(10, Kind::U16),
+    ],
+};
+pub const REGION_OF_INTEREST: Layout = Layout {
+    len: 10,
+    fields: &[
+        Field::new(0, Kind::U16),
+        Field::new(2, Kind::U16),
+        Field::new(4, Kind::U16),
+        Field::new(6, Kind::U16),
+        Field::new(8, Kind::U16),
+    ],
+};
+
+// Processing Unit
+pub const BACKLIGHT_COMPENSATION: Layout = single(Kind::U16);
+pub const BRIGHTNESS: Layout = single(Kind::I16);
+pub const CONTRAST: Layout = single(Kind::U16);
+pub const CONTRAST_AUTO: Layout = single(Kind::U8);
+pub const GAIN: Layout = single(Kind::U16);
+pub const POWER_LINE_FREQUENCY: Layout = single(Kind::U8);
+pub const HUE: Layout = single(Kind::I16);
+pub const HUE_AUTO: Layout = single(Kind::U8);
+pub const SATURATION: Layout = single(Kind::U16);
+pub const SHARPNESS: Layout = single(Kind::U16);
+pub const GAMMA: Layout = single(Kind::U16);
+pub const WHITE_BALANCE_TEMPERATURE: Layout = single(Kind::U16);
+pub const WHITE_BALANCE_TEMPERATURE_AUTO: Layout = single(Kind::U8);
+pub const WHITE_BALANCE_COMPONENT: Layout = Layout {
+    len: 4,
+    fields: &[Field::new(0, Kind::U16), Field::new(2, Kind::U16)],
+};
+pub const WHITE_BALANCE_COMPONENT_AUTO: Layout = single(Kind::U8);
+pub const DIGITAL_MULTIPLIER: Layout = single(Kind::U16);
+pub const DIGITAL_MULTIPLIER_LIMIT: Layout = single(Kind::U16);
+pub const ANALOG_VIDEO_STANDARD: Layout = single(Kind::U8);
+pub const ANALOG_LOCK_STATUS: Layout = single(Kind::U8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [(&str, Layout); 39] = [
+        ("SCANNING_MODE", SCANNING_MODE),
+        ("AE_MODE", AE_MODE),
+        ("AE_PRIORITY", AE_PRIORITY),
+        ("EXPOSURE_TIME_ABS", EXPOSURE_TIME_ABS),
+        ("EXPOSURE_TIME_REL", EXPOSURE_TIME_REL),
+        ("FOCUS_ABS", FOCUS_ABS),
+        ("FOCUS_REL", FOCUS_REL),
+        ("FOCUS_SIMPLE", FOCUS_SIMPLE),
+        ("FOCUS_AUTO", FOCUS_AUTO),
+        ("IRIS_ABS", IRIS_ABS),
+        ("IRIS_REL", IRIS_REL),
+        ("ZOOM_ABS", ZOOM_ABS),
+        ("ZOOM_REL", ZOOM_REL),
+        ("PAN_TILT_ABS", PAN_TILT_ABS),
+        ("PAN_TILT_REL", PAN_TILT_REL),
+        ("ROLL_ABS", ROLL_ABS),
+        ("ROLL_REL", ROLL_REL),
+        ("PRIVACY", PRIVACY),
+        ("WINDOW", WINDOW),
+        ("REGION_OF_INTEREST", REGION_OF_INTEREST),
+        ("BACKLIGHT_COMPENSATION", BACKLIGHT_COMPENSATION),
+        ("BRIGHTNESS", BRIGHTNESS),
+        ("CONTRAST", CONTRAST),
+        ("CONTRAST_AUTO", CONTRAST_AUTO),
+        ("GAIN", GAIN),
+        ("POWER_LINE_FREQUENCY", POWER_LINE_FREQUENCY),
+        ("HUE", HUE),
+        ("HUE_AUTO", HUE_AUTO),
+        ("SATURATION", SATURATION),
+        ("SHARPNESS", SHARPNESS),
+        ("GAMMA", GAMMA),
+        ("WHITE_BALANCE_TEMPERATURE", WHITE_BALANCE_TEMPERATURE),
+        (
+            "WHITE_BALANCE_TEMPERATURE_AUTO",
+            WHITE_BALANCE_TEMPERATURE_AUTO,
+        ),
+        ("WHITE_BALANCE_COMPONENT", WHITE_BALANCE_COMPONENT),
+        ("WHITE_BALANCE_COMPONENT_AUTO", WHITE_BALANCE_COMPONENT_AUTO),
+        ("DIGITAL_MULTIPLIER", DIGITAL_MULTIPLIER),
+        ("DIGITAL_MULTIPLIER_LIMIT", DIGITAL_MULTIPLIER_LIMIT),
+        ("ANALOG_VIDEO_STANDARD", ANALOG_VIDEO_STANDARD),
+        ("ANALOG_LOCK_STATUS", ANALOG_LOCK_STATUS),
+    ];
+
+    /// The smallest and largest value of each kind, as far as they fit in an `i32`.
+    fn extremes(kind: Kind) -> [i32; 2] {
+        match kind {
+            Kind::U8 => [0, u8::MAX as _],
+            Kind::I8 => [i8::MIN as _, i8::MAX as _],
+            Kind::U16 => [0, u16::MAX as _],
+            Kind::I16 => [i16::MIN as _, i16::MAX as _],
+            Kind::U32 => [0, i32::MAX],
+            Kind::I32 => [i32::MIN, i32::MAX],
+        }
+    }
+
+    #[test]
+    fn fields_fill_layouts() {
+        for (name, layout) in LAYOUTS {
+            let end = layout.fields.iter().map(|f| f.offset + f.kind.size()).max();
+            assert_eq!(end, Some(layout.len), "{name}");
+        }
+    }
+
+    #[test]
+    fn value_round_trip() {
+        for (name, layout) in LAYOUTS {
+            for i in 0..2 {
+                let values: Vec<i32> = layout.fields.iter().map(|f| extremes(f.kind)[i]).collect();
+                let data = layout.encode(&values);
+                assert_eq!(data.len(), layout.len, "{name}");
+                assert_eq!(layout.decode(&data), values, "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn byte_round_trip() {
+        for (name, layout) in LAYOUTS {
+            let data: Vec<u8> = (0..layout.len as u8).map(|i| 0x80 | (i * 0x11)).collect();
+            assert_eq!(layout.encode(&layout.decode(&data)), data, "{name}");
+        }
+    }
+
+    #[test]
+    fn sign_extension() {
+        assert_eq!(Field::new(0, Kind::I8).read(&[0xff]), -1);
+        assert_eq!(Field::new(0, Kind::U8).read(&[0xff]), 255);
+        assert_eq!(Field::new(0, Kind::I16).read(&[0xff, 0xff]), -1);
+        assert_eq!(Field::new(0, Kind::U16).read(&[0xff, 0xff]), 65535);
+        assert_eq!(Field::new(0, Kind::I16).read(&[0x00, 0x80]), -32768);
+        assert_eq!(Field::new(0, Kind::I32).read(&[0xff; 4]), -1);
+        assert_eq!(Field::new(0, Kind::U32).read(&[0xff; 4]), 0xffff_ffff);
+
+        assert_eq!(EXPOSURE_TIME_REL.decode(&[0xff]), [-1]);
+        assert_eq!(BRIGHTNESS.decode(&[0xff, 0xff]), [-1]);
+        assert_eq!(CONTRAST.decode(&[0xff, 0xff]), [65535]);
+        assert_eq!(ZOOM_REL.decode(&[0xff, 0xff, 0xff]), [-1, 255, 255]);
+        assert_eq!(PAN_TILT_REL.decode(&[0xff; 4]), [-1, 255, -1, 255]);
+    }
+
+    #[test]
+    fn pan_tilt_abs_is_little_endian() {
+        let data = PAN_TILT_ABS.encode(&[0x0102_0304, -2]);
+        assert_eq!(data, [0x04, 0x03, 0x02, 0x01, 0xfe, 0xff, 0xff, 0xff]);
+        assert_eq!(
+            PAN_TILT_ABS.decode(&[0x10, 0x0e, 0x00, 0x00, 0xf0, 0xf1, 0xff, 0xff]),
+            [3600, -3600]
+        );
+    }
+
+    #[test]
+    fn short_buffers_are_zero_padded() {
+        assert_eq!(Field::new(0, Kind::U16).read(&[0x34]), 0x34);
+        // The missing high byte is zero, so the value is not sign extended.
+        assert_eq!(Field::new(0, Kind::I16).read(&[0xff]), 0xff);
+        assert_eq!(Field::new(0, Kind::U32).read(&[0x01, 0x02]), 0x0201);
+        assert_eq!(Field::new(2, Kind::U16).read(&[0x01, 0x02]), 0);
+        assert_eq!(Field::new(8, Kind::I32).read(&[]), 0);
+        assert_eq!(PAN_TILT_ABS.decode(&[0x01, 0x00, 0x00, 0x00, 0x02]), [1, 2]);
+    }
+
+    #[test]
+    fn missing_values_encode_as_zero() {
+        assert_eq!(
+            WHITE_BALANCE_COMPONENT.encode(&[0x0102]),
+            [0x02, 0x01, 0x00, 0x00]
+        );
+        assert_eq!(ZOOM_REL.encode(&[]), [0x00; 3]);
+    }
+}
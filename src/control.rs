@@ -0,0 +1,575 @@
+use crate::{
+    codec::{self, Layout},
+    descriptors::{CameraControl, ProcessingControl},
+    Caps, Error,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    CameraTerminal,
+    ProcessingUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlId {
+    // Camera Terminal
+    ScanningMode,
+    AeMode,
+    AePriority,
+    ExposureTimeAbs,
+    ExposureTimeRel,
+    FocusAbs,
+    FocusRel,
+    FocusSimple,
+    FocusAuto,
+    IrisAbs,
+    IrisRel,
+    ZoomAbs,
+    ZoomRel,
+    PanTiltAbs,
+    PanTiltRel,
+    RollAbs,
+    RollRel,
+    Privacy,
+    Window,
+    RegionOfInterest,
+    // Processing Unit
+    BacklightCompensation,
+    Brightness,
+    Contrast,
+    ContrastAuto,
+    Gain,
+    PowerLineFrequency,
+    Hue,
+    HueAuto,
+    Saturation,
+    Sharpness,
+    Gamma,
+    WhiteBalanceTemperature,
+    WhiteBalanceTemperatureAuto,
+    WhiteBalanceComponent,
+    WhiteBalanceComponentAuto,
+    DigitalMultiplier,
+    DigitalMultiplierLimit,
+    AnalogVideoStandard,
+    AnalogLockStatus,
+}
+
+impl ControlId {
+    pub const ALL: [ControlId; 39] = [
+        ControlId::ScanningMode,
+        ControlId::AeMode,
+        ControlId::AePriority,
+        ControlId::ExposureTimeAbs,
+        ControlId::ExposureTimeRel,
+        ControlId::FocusAbs,
+        ControlId::FocusRel,
+        ControlId::FocusSimple,
+        ControlId::FocusAuto,
+        ControlId::IrisAbs,
+        ControlId::IrisRel,
+        ControlId::ZoomAbs,
+        ControlId::ZoomRel,
+        ControlId::PanTiltAbs,
+        ControlId::PanTiltRel,
+        ControlId::RollAbs,
+        ControlId::RollRel,
+        ControlId::Privacy,
+        ControlId::Window,
+        ControlId::RegionOfInterest,
+        ControlId::BacklightCompensation,
+        ControlId::Brightness,
+        ControlId::Contrast,
+        ControlId::ContrastAuto,
+        ControlId::Gain,
+        ControlId::PowerLineFrequency,
+        ControlId::Hue,
+        ControlId::HueAuto,
+        ControlId::Saturation,
+        ControlId::Sharpness,
+        ControlId::Gamma,
+        ControlId::WhiteBalanceTemperature,
+        ControlId::WhiteBalanceTemperatureAuto,
+        ControlId::WhiteBalanceComponent,
+        ControlId::WhiteBalanceComponentAuto,
+        ControlId::DigitalMultiplier,
+        ControlId::DigitalMultiplierLimit,
+        ControlId::AnalogVideoStandard,
+        ControlId::AnalogLockStatus,
+    ];
+
+    pub fn unit(self) -> UnitKind {
+        match self {
+            ControlId::ScanningMode
+            | ControlId::AeMode
+            | ControlId::AePriority
+            | ControlId::ExposureTimeAbs
+            | ControlId::ExposureTimeRel
+            | ControlId::FocusAbs
+            | ControlId::FocusRel
+            | ControlId::FocusSimple
+            | ControlId::FocusAuto
+            | ControlId::IrisAbs
+            | ControlId::IrisRel
+            | ControlId::ZoomAbs
+            | ControlId::ZoomRel
+            | ControlId::PanTiltAbs
+            | ControlId::PanTiltRel
+            | ControlId::RollAbs
+            | ControlId::RollRel
+            | ControlId::Privacy
+            | ControlId::Window
+            | ControlId::RegionOfInterest => UnitKind::CameraTerminal,
+            _ => UnitKind::ProcessingUnit,
+        }
+    }
+
+    /// Whether setting the control starts a movement or steps a value instead of setting a
+    /// state, so that sending it again does not leave the device as it was.
+    pub fn is_relative(self) -> bool {
+        matches!(
+            self,
+            ControlId::ExposureTimeRel
+                | ControlId::FocusRel
+                | ControlId::IrisRel
+                | ControlId::ZoomRel
+                | ControlId::PanTiltRel
+                | ControlId::RollRel
+        )
+    }
+
+    /// The `CS` selector sent in the high byte of wValue.
+    pub fn selector(self) -> u8 {
+        match self {
+            ControlId::ScanningMode => 0x01,
+            ControlId::AeMode => 0x02,
+            ControlId::AePriority => 0x03,
+            ControlId::ExposureTimeAbs => 0x04,
+            ControlId::ExposureTimeRel => 0x05,
+            ControlId::FocusAbs => 0x06,
+            ControlId::FocusRel => 0x07,
+            ControlId::FocusAuto => 0x08,
+            ControlId::IrisAbs => 0x09,
+            ControlId::IrisRel => 0x0a,
+            ControlId::ZoomAbs => 0x0b,
+            ControlId::ZoomRel => 0x0c,
+            ControlId::PanTiltAbs => 0x0d,
+            ControlId::PanTiltRel => 0x0e,
+            ControlId::RollAbs => 0x0f,
+            ControlId::RollRel => 0x10,
+            ControlId::Privacy => 0x11,
+            ControlId::FocusSimple => 0x12,
+            ControlId::Window => 0x13,
+            ControlId::RegionOfInterest => 0x14,
+            ControlId::BacklightCompensation => 0x01,
+            ControlId::Brightness => 0x02,
+            ControlId::Contrast => 0x03,
+            ControlId::Gain => 0x04,
+            ControlId::PowerLineFrequency => 0x05,
+            ControlId::Hue => 0x06,
+            ControlId::Saturation => 0x07,
+            ControlId::Sharpness => 0x08,
+            ControlId::Gamma => 0x09,
+            ControlId::WhiteBalanceTemperature => 0x0a,
+            ControlId::WhiteBalanceTemperatureAuto => 0x0b,
+            ControlId::WhiteBalanceComponent => 0x0c,
+            ControlId::WhiteBalanceComponentAuto => 0x0d,
+            ControlId::DigitalMultiplier => 0x0e,
+            ControlId::DigitalMultiplierLimit => 0x0f,
+            ControlId::HueAuto => 0x10,
+            ControlId::AnalogVideoStandard => 0x11,
+            ControlId::AnalogLockStatus => 0x12,
+            ControlId::ContrastAuto => 0x13,
+        }
+    }
+
+    pub fn layout(self) -> Layout {
+        match self {
+            ControlId::ScanningMode => codec::SCANNING_MODE,
+            ControlId::AeMode => codec::AE_MODE,
+            ControlId::AePriority => codec::AE_PRIORITY,
+            ControlId::ExposureTimeAbs => codec::EXPOSURE_TIME_ABS,
+            ControlId::ExposureTimeRel => codec::EXPOSURE_TIME_REL,
+            ControlId::FocusAbs => codec::FOCUS_ABS,
+            ControlId::FocusRel => codec::FOCUS_REL,
+            ControlId::FocusSimple => codec::FOCUS_SIMPLE,
+            ControlId::FocusAuto => codec::FOCUS_AUTO,
+            ControlId::IrisAbs => codec::IRIS_ABS,
+            ControlId::IrisRel => codec::IRIS_REL,
+            ControlId::ZoomAbs => codec::ZOOM_ABS,
+            ControlId::ZoomRel => codec::ZOOM_REL,
+            ControlId::PanTiltAbs => codec::PAN_TILT_ABS,
+            ControlId::PanTiltRel => codec::PAN_TILT_REL,
+            ControlId::RollAbs => codec::ROLL_ABS,
+            ControlId::RollRel => codec::ROLL_REL,
+            ControlId::Privacy => codec::PRIVACY,
+            ControlId::Window => codec::WINDOW,
+            ControlId::RegionOfInterest => codec::REGION_OF_INTEREST,
+            ControlId::BacklightCompensation => codec::BACKLIGHT_COMPENSATION,
+            ControlId::Brightness => codec::BRIGHTNESS,
+            ControlId::Contrast => codec::CONTRAST,
+            ControlId::ContrastAuto => codec::CONTRAST_AUTO,
+            ControlId::Gain => codec::GAIN,
+            ControlId::PowerLineFrequency => codec::POWER_LINE_FREQUENCY,
+            ControlId::Hue => codec::HUE,
+            ControlId::HueAuto => codec::HUE_AUTO,
+            ControlId::Saturation => codec::SATURATION,
+            ControlId::Sharpness => codec::SHARPNESS,
+            ControlId::Gamma => codec::GAMMA,
+            ControlId::WhiteBalanceTemperature => codec::WHITE_BALANCE_TEMPERATURE,
+            ControlId::WhiteBalanceTemperatureAuto => codec::WHITE_BALANCE_TEMPERATURE_AUTO,
+            ControlId::WhiteBalanceComponent => codec::WHITE_BALANCE_COMPONENT,
+            ControlId::WhiteBalanceComponentAuto => codec::WHITE_BALANCE_COMPONENT_AUTO,
+            ControlId::DigitalMultiplier => codec::DIGITAL_MULTIPLIER,
+            ControlId::DigitalMultiplierLimit => codec::DIGITAL_MULTIPLIER_LIMIT,
+            ControlId::AnalogVideoStandard => codec::ANALOG_VIDEO_STANDARD,
+            ControlId::AnalogLockStatus => codec::ANALOG_LOCK_STATUS,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ControlId::ScanningMode => "scanning_mode",
+            ControlId::AeMode => "ae_mode",
+            ControlId::AePriority => "ae_priority",
+            ControlId::ExposureTimeAbs => "exposure_time_abs",
+            ControlId::ExposureTimeRel => "exposure_time_rel",
+            ControlId::FocusAbs => "focus_abs",
+            ControlId::FocusRel => "focus_rel",
+            ControlId::FocusSimple => "focus_simple",
+            ControlId::FocusAuto => "focus_auto",
+            ControlId::IrisAbs => "iris_abs",
+            ControlId::IrisRel => "iris_rel",
+            ControlId::ZoomAbs => "zoom_abs",
+            ControlId::ZoomRel => "zoom_rel",
+            ControlId::PanTiltAbs => "pan_tilt_abs",
+            ControlId::PanTiltRel => "pan_tilt_rel",
+            ControlId::RollAbs => "roll_abs",
+            ControlId::RollRel => "roll_rel",
+            ControlId::Privacy => "privacy",
+            ControlId::Window => "window",
+            ControlId::RegionOfInterest => "region_of_interest",
+            ControlId::BacklightCompensation => "backlight_compensation",
+            ControlId::Brightness => "brightness",
+            ControlId::Contrast => "contrast",
+            ControlId::ContrastAuto => "contrast_auto",
+            ControlId::Gain => "gain",
+            ControlId::PowerLineFrequency => "power_line_frequency",
+            ControlId::Hue => "hue",
+            ControlId::HueAuto => "hue_auto",
+            ControlId::Saturation => "saturation",
+            ControlId::Sharpness => "sharpness",
+            ControlId::Gamma => "gamma",
+            ControlId::WhiteBalanceTemperature => "white_balance_temperature",
+            ControlId::WhiteBalanceTemperatureAuto => "white_balance_temperature_auto",
+            ControlId::WhiteBalanceComponent => "white_balance_component",
+            ControlId::WhiteBalanceComponentAuto => "white_balance_component_auto",
+            ControlId::DigitalMultiplier => "digital_multiplier",
+            ControlId::DigitalMultiplierLimit => "digital_multiplier_limit",
+            ControlId::AnalogVideoStandard => "analog_video_standard",
+            ControlId::AnalogLockStatus => "analog_lock_status",
+        }
+    }
+}
+
+impl FromStr for ControlId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ControlId::ALL
+            .into_iter()
+            .find(|c| c.name() == s)
+            .ok_or(Error::UnknownControl)
+    }
+}
+
+impl From<CameraControl> for ControlId {
+    fn from(c: CameraControl) -> Self {
+        match c {
+            CameraControl::ScanningMode => ControlId::ScanningMode,
+            CameraControl::AeMode => ControlId::AeMode,
+            CameraControl::AePriority => ControlId::AePriority,
+            CameraControl::ExposureTimeAbs => ControlId::ExposureTimeAbs,
+            CameraControl::ExposureTimeRel => ControlId::ExposureTimeRel,
+            CameraControl::FocusAbs => ControlId::FocusAbs,
+            CameraControl::FocusRel => ControlId::FocusRel,
+            CameraControl::IrisAbs => ControlId::IrisAbs,
+            CameraControl::IrisRel => ControlId::IrisRel,
+            CameraControl::ZoomAbs => ControlId::ZoomAbs,
+            CameraControl::ZoomRel => ControlId::ZoomRel,
+            CameraControl::PanTiltAbs => ControlId::PanTiltAbs,
+            CameraControl::PanTiltRel => ControlId::PanTiltRel,
+            CameraControl::RollAbs => ControlId::RollAbs,
+            CameraControl::RollRel => ControlId::RollRel,
+            CameraControl::FocusAuto => ControlId::FocusAuto,
+            CameraControl::Privacy => ControlId::Privacy,
+            CameraControl::FocusSimple => ControlId::FocusSimple,
+            CameraControl::Window => ControlId::Window,
+            CameraControl::RegionOfInterest => ControlId::RegionOfInterest,
+        }
+    }
+}
+
+impl From<ProcessingControl> for ControlId {
+    fn from(c: ProcessingControl) -> Self {
+        match c {
+            ProcessingControl::Brightness => ControlId::Brightness,
+            ProcessingControl::Contrast => ControlId::Contrast,
+            ProcessingControl::Hue => ControlId::Hue,
+            ProcessingControl::Saturation => ControlId::Saturation,
+            ProcessingControl::Sharpness => ControlId::Sharpness,
+            ProcessingControl::Gamma => ControlId::Gamma,
+            ProcessingControl::WhiteBalanceTemperature => ControlId::WhiteBalanceTemperature,
+            ProcessingControl::WhiteBalanceComponent => ControlId::WhiteBalanceComponent,
+            ProcessingControl::BacklightCompensation => ControlId::BacklightCompensation,
+            ProcessingControl::Gain => ControlId::Gain,
+            ProcessingControl::PowerLineFrequency => ControlId::PowerLineFrequency,
+            ProcessingControl::HueAuto => ControlId::HueAuto,
+            ProcessingControl::WhiteBalanceTemperatureAuto => {
+                ControlId::WhiteBalanceTemperatureAuto
+            }
+            ProcessingControl::WhiteBalanceComponentAuto => ControlId::WhiteBalanceComponentAuto,
+            ProcessingControl::DigitalMultiplier => ControlId::DigitalMultiplier,
+            ProcessingControl::DigitalMultiplierLimit => ControlId::DigitalMultiplierLimit,
+            ProcessingControl::AnalogVideoStandard => ControlId::AnalogVideoStandard,
+            ProcessingControl::AnalogLockStatus => ControlId::AnalogLockStatus,
+            ProcessingControl::ContrastAuto => ControlId::ContrastAuto,
+        }
+    }
+}
+
+/// A decoded control payload, one entry per field of the control's layout.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Value(pub Vec<i32>);
+
+impl From<i32> for Value {
+    fn from(v: i32) -> Self {
+        Value(vec![v])
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for Value {
+    fn from(v: [i32; N]) -> Self {
+        Value(v.to_vec())
+    }
+}
+
+impl From<Vec<i32>> for Value {
+    fn from(v: Vec<i32>) -> Self {
+        Value(v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum AeMode {
+    Manual = 0x01,
+    Auto = 0x02,
+    ShutterPriority = 0x04,
+    AperturePriority = 0x08,
+}
+
+impl AeMode {
+    pub const ALL: [AeMode; 4] = [
+        AeMode::Manual,
+        AeMode::Auto,
+        AeMode::ShutterPriority,
+        AeMode::AperturePriority,
+    ];
+
+    pub fn from_bits(bits: u8) -> Option<Self> {
+        AeMode::ALL.into_iter().find(|&m| m as u8 == bits)
+    }
+
+    /// Decodes the mask of supported modes reported by GET_RES.
+    pub fn from_mask(mask: u8) -> Vec<Self> {
+        AeMode::ALL
+            .into_iter()
+            .filter(|&m| mask & m as u8 != 0)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FocusSimpleRange {
+    FullRange = 0x00,
+    Macro = 0x01,
+    People = 0x02,
+    Scene = 0x03,
+}
+
+impl FocusSimpleRange {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0x00 => Some(FocusSimpleRange::FullRange),
+            0x01 => Some(FocusSimpleRange::Macro),
+            0x02 => Some(FocusSimpleRange::People),
+            0x03 => Some(FocusSimpleRange::Scene),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ScanningMode {
+    Interlaced = 0x00,
+    Progressive = 0x01,
+}
+
+impl ScanningMode {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0x00 => Some(ScanningMode::Interlaced),
+            0x01 => Some(ScanningMode::Progressive),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PowerLineFrequency {
+    Disabled = 0x00,
+    Hz50 = 0x01,
+    Hz60 = 0x02,
+    Auto = 0x03,
+}
+
+impl PowerLineFrequency {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0x00 => Some(PowerLineFrequency::Disabled),
+            0x01 => Some(PowerLineFrequency::Hz50),
+            0x02 => Some(PowerLineFrequency::Hz60),
+            0x03 => Some(PowerLineFrequency::Auto),
+            _ => None,
+        }
+    }
+}
+
+/// Capabilities and state of a control, as reported by GET_INFO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ControlInfo {
+    pub get: bool,
+    pub set: bool,
+    pub disabled_auto: bool,
+    pub autoupdate: bool,
+    pub asynchronous: bool,
+    pub disabled_commit: bool,
+}
+
+impl ControlInfo {
+    pub fn from_bits(bits: u8) -> Self {
+        ControlInfo {
+            get: bits & 0x01 != 0,
+            set: bits & 0x02 != 0,
+            disabled_auto: bits & 0x04 != 0,
+            autoupdate: bits & 0x08 != 0,
+            asynchronous: bits & 0x10 != 0,
+            disabled_commit: bits & 0x20 != 0,
+        }
+    }
+
+    /// Whether SET_CUR can currently succeed.
+    pub fn writable(&self) -> bool {
+        self.set && !self.disabled_auto && !self.disabled_commit
+    }
+}
+
+/// Optical zoom followed by the digital multiplier, as a single range. Shared by the backends,
+/// which only read and write the two controls.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EffectiveZoom {
+    pub optical: Caps,
+    pub digital: Caps,
+    /// `PU_DIGITAL_MULTIPLIER_LIMIT`, when the device has one.
+    pub limit: Option<i32>,
+}
+
+impl EffectiveZoom {
+    fn digital_max(&self) -> i32 {
+        self.limit
+            .unwrap_or(self.digital.max)
+            .clamp(self.digital.min, self.digital.max)
+    }
+
+    pub fn caps(&self) -> Caps {
+        Caps {
+            min: self.optical.min,
+            max: self.optical.max + self.digital_max() - self.digital.min,
+            res: self.optical.res,
+            def: self.optical.def,
+        }
+    }
+
+    pub fn value(&self, optical: i32, digital: i32) -> i32 {
+        if optical < self.optical.max {
+            return optical;
+        }
+        let digital = digital.clamp(self.digital.min, self.digital_max());
+        self.optical.max + digital - self.digital.min
+    }
+
+    /// Splits `value`, clamped to `caps()`, into the two controls. The digital multiplier is
+    /// only raised once the optical zoom is at its end.
+    pub fn set(
+        &self,
+        value: i32,
+        optical_set: impl FnOnce(i32) -> Result<(), Error>,
+        digital_set: impl FnOnce(i32) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let value = value.clamp(self.optical.min, self.caps().max);
+        if value <= self.optical.max {
+            digital_set(self.digital.min)?;
+            optical_set(value)
+        } else {
+            optical_set(self.optical.max)?;
+            digital_set(self.digital.min + value - self.optical.max)
+        }
+    }
+}
+
+/// The value of `VC_REQUEST_ERROR_CODE_CONTROL` explaining why a request failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+#[repr(u8)]
+pub enum RequestErrorCode {
+    #[error("not ready")]
+    NotReady = 0x01,
+    #[error("wrong state")]
+    WrongState = 0x02,
+    #[error("power")]
+    Power = 0x03,
+    #[error("out of range")]
+    OutOfRange = 0x04,
+    #[error("invalid unit")]
+    InvalidUnit = 0x05,
+    #[error("invalid control")]
+    InvalidControl = 0x06,
+    #[error("invalid request")]
+    InvalidRequest = 0x07,
+    #[error("invalid value within range")]
+    InvalidValueWithinRange = 0x08,
+    #[error("unknown")]
+    Unknown = 0xff,
+}
+
+impl RequestErrorCode {
+    /// Returns `None` for `0x00`, which means no error.
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0x00 => None,
+            0x01 => Some(RequestErrorCode::NotReady),
+            0x02 => Some(RequestErrorCode::WrongState),
+            0x03 => Some(RequestErrorCode::Power),
+            0x04 => Some(RequestErrorCode::OutOfRange),
+            0x05 => Some(RequestErrorCode::InvalidUnit),
+            0x06 => Some(RequestErrorCode::InvalidControl),
+            0x07 => Some(RequestErrorCode::InvalidRequest),
+            0x08 => Some(RequestErrorCode::InvalidValueWithinRange),
+            _ => Some(RequestErrorCode::Unknown),
+        }
+    }
+}
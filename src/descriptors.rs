@@ -0,0 +1,696 @@
+use crate::Error;
+use std::ops::Deref;
+
+#[derive(Debug, Clone, Default)]
+pub struct VideoControl {
+    pub header: Header,
+    pub input_terminals: Vec<InputTerminal>,
+    pub output_terminals: Vec<OutputTerminal>,
+    pub selector_units: Vec<SelectorUnit>,
+    pub processing_units: Vec<ProcessingUnit>,
+    pub encoding_units: Vec<EncodingUnit>,
+    pub extension_units: Vec<ExtensionUnit>,
+}
+
+impl VideoControl {
+    /// Parses the class-specific descriptors of a VideoControl interface, one descriptor per item.
+    /// Descriptors of other types are skipped.
+    pub fn parse<I>(descriptors: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: Deref<Target = [u8]>,
+    {
+        let mut vc = VideoControl::default();
+        let mut has_header = false;
+
+        for d in descriptors {
+            has_header |= vc.push(&d)?;
+        }
+
+        if !has_header {
+            return Err(Error::InvalidDescriptor);
+        }
+        Ok(vc)
+    }
+
+    /// Like [`parse`](Self::parse), but skips descriptors that are malformed or cannot be parsed
+    /// and does not require a header, so a camera with a broken or vendor-specific class
+    /// descriptor can still be opened.
+    pub fn parse_lenient<I>(descriptors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Deref<Target = [u8]>,
+    {
+        let mut vc = VideoControl::default();
+        for d in descriptors {
+            let _ = vc.push(&d);
+        }
+        vc
+    }
+
+    /// Adds one descriptor, returning whether it was the header.
+    fn push(&mut self, d: &[u8]) -> Result<bool, Error> {
+        if d.len() < 3 || d[0] as usize != d.len() {
+            return Err(Error::InvalidDescriptor);
+        }
+        if d[1] != DescriptorType::CSInterface as u8 {
+            return Ok(false);
+        }
+
+        match d[2] {
+            s if s == Subtype::Header as u8 => {
+                self.header = Header::parse(d)?;
+                return Ok(true);
+            }
+            s if s == Subtype::InputTerminal as u8 => {
+                self.input_terminals.push(InputTerminal::parse(d)?)
+            }
+            s if s == Subtype::OutputTerminal as u8 => {
+                self.output_terminals.push(OutputTerminal::parse(d)?)
+            }
+            s if s == Subtype::SelectorUnit as u8 => {
+                self.selector_units.push(SelectorUnit::parse(d)?)
+            }
+            s if s == Subtype::ProcessingUnit as u8 => {
+                self.processing_units.push(ProcessingUnit::parse(d)?)
+            }
+            s if s == Subtype::ExtensionUnit as u8 => {
+                self.extension_units.push(ExtensionUnit::parse(d)?)
+            }
+            s if s == Subtype::EncodingUnit as u8 => {
+                self.encoding_units.push(EncodingUnit::parse(d)?)
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    /// Parses a captured blob of concatenated VideoControl class-specific descriptors.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, Error> {
+        let mut descriptors = vec![];
+        while !bytes.is_empty() {
+            let len = bytes[0] as usize;
+            if len < 2 || len > bytes.len() {
+                return Err(Error::InvalidDescriptor);
+            }
+            let (d, rest) = bytes.split_at(len);
+            descriptors.push(d);
+            bytes = rest;
+        }
+        Self::parse(descriptors)
+    }
+
+    pub fn camera_terminal(&self) -> Option<&InputTerminal> {
+        self.input_terminals.iter().find(|t| t.camera.is_some())
+    }
+
+    pub fn processing_unit(&self) -> Option<&ProcessingUnit> {
+        self.processing_units.first()
+    }
+
+    pub fn supported_controls(&self) -> SupportedControls {
+        SupportedControls {
+            camera: self
+                .camera_terminal()
+                .and_then(|t| t.camera.as_ref())
+                .map(|c| c.supported())
+                .unwrap_or_default(),
+            processing: self
+                .processing_unit()
+                .map(|u| u.supported())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SupportedControls {
+    pub camera: Vec<CameraControl>,
+    pub processing: Vec<ProcessingControl>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Header {
+    /// BCD encoded, e.g. `0x0150` for UVC 1.5.
+    pub bcd_uvc: u16,
+    pub total_length: u16,
+    /// In Hz.
+    pub clock_frequency: u32,
+    pub interface_numbers: Vec<u8>,
+}
+
+impl Header {
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        let n = u8_at(d, 11)? as usize;
+        Ok(Header {
+            bcd_uvc: u16_at(d, 3)?,
+            total_length: u16_at(d, 5)?,
+            clock_frequency: u32_at(d, 7)?,
+            interface_numbers: bytes_at(d, 12, n)?.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InputTerminal {
+    pub id: u8,
+    pub terminal_type: u16,
+    pub assoc_terminal: u8,
+    pub terminal_string: u8,
+    pub camera: Option<CameraTerminal>,
+}
+
+impl InputTerminal {
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        let terminal_type = u16_at(d, 4)?;
+        let camera = if terminal_type == TerminalType::Camera as u16 {
+            let n = u8_at(d, 14)? as usize;
+            Some(CameraTerminal {
+                objective_focal_length_min: u16_at(d, 8)?,
+                objective_focal_length_max: u16_at(d, 10)?,
+                ocular_focal_length: u16_at(d, 12)?,
+                controls: bytes_at(d, 15, n)?.to_vec(),
+            })
+        } else {
+            None
+        };
+        Ok(InputTerminal {
+            id: u8_at(d, 3)?,
+            terminal_type,
+            assoc_terminal: u8_at(d, 6)?,
+            terminal_string: u8_at(d, 7)?,
+            camera,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CameraTerminal {
+    pub objective_focal_length_min: u16,
+    pub objective_focal_length_max: u16,
+    pub ocular_focal_length: u16,
+    /// Raw `bmControls` bitmap.
+    pub controls: Vec<u8>,
+}
+
+impl CameraTerminal {
+    pub fn supported(&self) -> Vec<CameraControl> {
+        CameraControl::ALL
+            .into_iter()
+            .filter(|&c| bit_set(&self.controls, c as _))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum CameraControl {
+    ScanningMode = 0,
+    AeMode = 1,
+    AePriority = 2,
+    ExposureTimeAbs = 3,
+    ExposureTimeRel = 4,
+    FocusAbs = 5,
+    FocusRel = 6,
+    IrisAbs = 7,
+    IrisRel = 8,
+    ZoomAbs = 9,
+    ZoomRel = 10,
+    PanTiltAbs = 11,
+    PanTiltRel = 12,
+    RollAbs = 13,
+    RollRel = 14,
+    FocusAuto = 17,
+    Privacy = 18,
+    FocusSimple = 19,
+    Window = 20,
+    RegionOfInterest = 21,
+}
+
+impl CameraControl {
+    pub const ALL: [CameraControl; 20] = [
+        CameraControl::ScanningMode,
+        CameraControl::AeMode,
+        CameraControl::AePriority,
+        CameraControl::ExposureTimeAbs,
+        CameraControl::ExposureTimeRel,
+        CameraControl::FocusAbs,
+        CameraControl::FocusRel,
+        CameraControl::IrisAbs,
+        CameraControl::IrisRel,
+        CameraControl::ZoomAbs,
+        CameraControl::ZoomRel,
+        CameraControl::PanTiltAbs,
+        CameraControl::PanTiltRel,
+        CameraControl::RollAbs,
+        CameraControl::RollRel,
+        CameraControl::FocusAuto,
+        CameraControl::Privacy,
+        CameraControl::FocusSimple,
+        CameraControl::Window,
+        CameraControl::RegionOfInterest,
+    ];
+}
+
+#[derive(Debug, Clone)]
+pub struct OutputTerminal {
+    pub id: u8,
+    pub terminal_type: u16,
+    pub assoc_terminal: u8,
+    pub source_id: u8,
+    pub terminal_string: u8,
+}
+
+impl OutputTerminal {
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        Ok(OutputTerminal {
+            id: u8_at(d, 3)?,
+            terminal_type: u16_at(d, 4)?,
+            assoc_terminal: u8_at(d, 6)?,
+            source_id: u8_at(d, 7)?,
+            terminal_string: u8_at(d, 8)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SelectorUnit {
+    pub id: u8,
+    pub source_ids: Vec<u8>,
+    pub selector_string: u8,
+}
+
+impl SelectorUnit {
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        let p = u8_at(d, 4)? as usize;
+        Ok(SelectorUnit {
+            id: u8_at(d, 3)?,
+            source_ids: bytes_at(d, 5, p)?.to_vec(),
+            selector_string: u8_at(d, 5 + p)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessingUnit {
+    pub id: u8,
+    pub source_id: u8,
+    pub max_multiplier: u16,
+    /// Raw `bmControls` bitmap.
+    pub controls: Vec<u8>,
+    pub processing_string: u8,
+    /// Absent before UVC 1.1.
+    pub video_standards: Option<u8>,
+}
+
+impl ProcessingUnit {
+    pub fn supported(&self) -> Vec<ProcessingControl> {
+        ProcessingControl::ALL
+            .into_iter()
+            .filter(|&c| bit_set(&self.controls, c as _))
+            .collect()
+    }
+
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        let n = u8_at(d, 7)? as usize;
+        Ok(ProcessingUnit {
+            id: u8_at(d, 3)?,
+            source_id: u8_at(d, 4)?,
+            max_multiplier: u16_at(d, 5)?,
+            controls: bytes_at(d, 8, n)?.to_vec(),
+            processing_string: u8_at(d, 8 + n)?,
+            video_standards: d.get(9 + n).copied(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ProcessingControl {
+    Brightness = 0,
+    Contrast = 1,
+    Hue = 2,
+    Saturation = 3,
+    Sharpness = 4,
+    Gamma = 5,
+    WhiteBalanceTemperature = 6,
+    WhiteBalanceComponent = 7,
+    BacklightCompensation = 8,
+    Gain = 9,
+    PowerLineFrequency = 10,
+    HueAuto = 11,
+    WhiteBalanceTemperatureAuto = 12,
+    WhiteBalanceComponentAuto = 13,
+    DigitalMultiplier = 14,
+    DigitalMultiplierLimit = 15,
+    AnalogVideoStandard = 16,
+    AnalogLockStatus = 17,
+    ContrastAuto = 18,
+}
+
+impl ProcessingControl {
+    pub const ALL: [ProcessingControl; 19] = [
+        ProcessingControl::Brightness,
+        ProcessingControl::Contrast,
+        ProcessingControl::Hue,
+        ProcessingControl::Saturation,
+        ProcessingControl::Sharpness,
+        ProcessingControl::Gamma,
+        ProcessingControl::WhiteBalanceTemperature,
+        ProcessingControl::WhiteBalanceComponent,
+        ProcessingControl::BacklightCompensation,
+        ProcessingControl::Gain,
+        ProcessingControl::PowerLineFrequency,
+        ProcessingControl::HueAuto,
+        ProcessingControl::WhiteBalanceTemperatureAuto,
+        ProcessingControl::WhiteBalanceComponentAuto,
+        ProcessingControl::DigitalMultiplier,
+        ProcessingControl::DigitalMultiplierLimit,
+        ProcessingControl::AnalogVideoStandard,
+        ProcessingControl::AnalogLockStatus,
+        ProcessingControl::ContrastAuto,
+    ];
+}
+
+#[derive(Debug, Clone)]
+pub struct EncodingUnit {
+    pub id: u8,
+    pub source_id: u8,
+    pub encoding_string: u8,
+    pub controls: Vec<u8>,
+    pub controls_runtime: Vec<u8>,
+}
+
+impl EncodingUnit {
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        let n = u8_at(d, 6)? as usize;
+        Ok(EncodingUnit {
+            id: u8_at(d, 3)?,
+            source_id: u8_at(d, 4)?,
+            encoding_string: u8_at(d, 5)?,
+            controls: bytes_at(d, 7, n)?.to_vec(),
+            controls_runtime: bytes_at(d, 7 + n, n)?.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExtensionUnit {
+    pub id: u8,
+    /// `guidExtensionCode` as it appears on the wire.
+    pub guid: [u8; 16],
+    pub num_controls: u8,
+    pub source_ids: Vec<u8>,
+    pub controls: Vec<u8>,
+    pub extension_string: u8,
+}
+
+impl ExtensionUnit {
+    fn parse(d: &[u8]) -> Result<Self, Error> {
+        let p = u8_at(d, 21)? as usize;
+        let n = u8_at(d, 22 + p)? as usize;
+        Ok(ExtensionUnit {
+            id: u8_at(d, 3)?,
+            guid: bytes_at(d, 4, 16)?.try_into().unwrap(),
+            num_controls: u8_at(d, 20)?,
+            source_ids: bytes_at(d, 22, p)?.to_vec(),
+            controls: bytes_at(d, 23 + p, n)?.to_vec(),
+            extension_string: u8_at(d, 23 + p + n)?,
+        })
+    }
+}
+
+/// The ids of the first Input Terminal and Processing Unit, read from their fourth byte without
+/// parsing the rest of the descriptors.
+pub(crate) fn first_unit_ids<I>(descriptors: I) -> (Option<u8>, Option<u8>)
+where
+    I: IntoIterator,
+    I::Item: Deref<Target = [u8]>,
+{
+    let mut it = None;
+    let mut pu = None;
+    for d in descriptors {
+        let [_, ty, subtype, id, ..] = *d else {
+            continue;
+        };
+        if ty != DescriptorType::CSInterface as u8 {
+            continue;
+        }
+        if subtype == Subtype::InputTerminal as u8 {
+            it = it.or(Some(id));
+        } else if subtype == Subtype::ProcessingUnit as u8 {
+            pu = pu.or(Some(id));
+        }
+    }
+    (it, pu)
+}
+
+fn bit_set(bitmap: &[u8], bit: u8) -> bool {
+    bitmap
+        .get(bit as usize / 8)
+        .is_some_and(|b| b & (1 << (bit % 8)) != 0)
+}
+
+fn bytes_at(d: &[u8], offset: usize, len: usize) -> Result<&[u8], Error> {
+    d.get(offset..offset + len).ok_or(Error::InvalidDescriptor)
+}
+
+fn u8_at(d: &[u8], offset: usize) -> Result<u8, Error> {
+    d.get(offset).copied().ok_or(Error::InvalidDescriptor)
+}
+
+fn u16_at(d: &[u8], offset: usize) -> Result<u16, Error> {
+    Ok(u16::from_le_bytes(
+        bytes_at(d, offset, 2)?.try_into().unwrap(),
+    ))
+}
+
+fn u32_at(d: &[u8], offset: usize) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(
+        bytes_at(d, offset, 4)?.try_into().unwrap(),
+    ))
+}
+
+#[repr(u8)]
+enum DescriptorType {
+    CSInterface = 0x24,
+}
+
+#[repr(u8)]
+enum Subtype {
+    Header = 0x01,
+    InputTerminal = 0x02,
+    OutputTerminal = 0x03,
+    SelectorUnit = 0x04,
+    ProcessingUnit = 0x05,
+    ExtensionUnit = 0x06,
+    EncodingUnit = 0x07,
+}
+
+#[repr(u16)]
+enum TerminalType {
+    Camera = 0x0201,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The VideoControl interface of a Logitech C920 as listed by `lsusb -v`, with its extension
+    /// units cut down to one. The interrupt endpoint and its class-specific descriptor are kept.
+    #[rustfmt::skip]
+    const C920: &[u8] = &[
+        // Header: UVC 1.00, 30 MHz, streaming interface 1
+        0x0d, 0x24, 0x01, 0x00, 0x01, 0xd6, 0x00, 0x80, 0xc3, 0xc9, 0x01, 0x01, 0x01,
+        // Camera input terminal 1, bmControls 0x020a2e
+        0x12, 0x24, 0x02, 0x01, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x03, 0x2e, 0x0a, 0x02,
+        // Processing unit 3 from 1, wMaxMultiplier 16384, bmControls 0x175b
+        0x0b, 0x24, 0x05, 0x03, 0x01, 0x00, 0x40, 0x02, 0x5b, 0x17, 0x00,
+        // Extension unit 6 from 3, 8 controls
+        0x1b, 0x24, 0x06, 0x06,
+        0xe4, 0x8e, 0x67, 0x69, 0x0f, 0x41, 0xdb, 0x40, 0xa8, 0x50, 0x74, 0x20, 0xd7, 0xd8, 0x24, 0x0e,
+        0x08, 0x01, 0x03, 0x02, 0xff, 0x00, 0x00,
+        // Output terminal 4 (USB streaming) from 6
+        0x09, 0x24, 0x03, 0x04, 0x01, 0x01, 0x00, 0x06, 0x00,
+        // Interrupt endpoint 0x83 and its class-specific descriptor
+        0x07, 0x05, 0x83, 0x03, 0x40, 0x00, 0x08,
+        0x05, 0x25, 0x03, 0x40, 0x00,
+    ];
+
+    #[test]
+    fn c920() {
+        let vc = VideoControl::from_bytes(C920).unwrap();
+
+        assert_eq!(vc.header.bcd_uvc, 0x0100);
+        assert_eq!(vc.header.total_length, 0x00d6);
+        assert_eq!(vc.header.clock_frequency, 30_000_000);
+        assert_eq!(vc.header.interface_numbers, [1]);
+
+        let it = vc.camera_terminal().unwrap();
+        assert_eq!((it.id, it.terminal_type), (1, 0x0201));
+        assert_eq!(it.camera.as_ref().unwrap().controls, [0x2e, 0x0a, 0x02]);
+
+        let pu = vc.processing_unit().unwrap();
+        assert_eq!((pu.id, pu.source_id, pu.max_multiplier), (3, 1, 16384));
+        assert_eq!(pu.controls, [0x5b, 0x17]);
+        assert_eq!(pu.video_standards, None);
+
+        let xu = &vc.extension_units[0];
+        assert_eq!((xu.id, xu.num_controls), (6, 8));
+        assert_eq!(xu.guid[..4], [0xe4, 0x8e, 0x67, 0x69]);
+        assert_eq!(xu.source_ids, [3]);
+        assert_eq!(xu.controls, [0xff, 0x00]);
+
+        let ot = &vc.output_terminals[0];
+        assert_eq!((ot.id, ot.terminal_type, ot.source_id), (4, 0x0101, 6));
+
+        assert!(vc.selector_units.is_empty());
+        assert!(vc.encoding_units.is_empty());
+    }
+
+    #[test]
+    fn c920_supported_controls() {
+        let supported = VideoControl::from_bytes(C920).unwrap().supported_controls();
+
+        assert_eq!(
+            supported.camera,
+            [
+                CameraControl::AeMode,
+                CameraControl::AePriority,
+                CameraControl::ExposureTimeAbs,
+                CameraControl::FocusAbs,
+                CameraControl::ZoomAbs,
+                CameraControl::PanTiltAbs,
+                CameraControl::FocusAuto,
+            ]
+        );
+        assert_eq!(
+            supported.processing,
+            [
+                ProcessingControl::Brightness,
+                ProcessingControl::Contrast,
+                ProcessingControl::Saturation,
+                ProcessingControl::Sharpness,
+                ProcessingControl::WhiteBalanceTemperature,
+                ProcessingControl::BacklightCompensation,
+                ProcessingControl::Gain,
+                ProcessingControl::PowerLineFrequency,
+                ProcessingControl::WhiteBalanceTemperatureAuto,
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        for len in [1, 12, 20, C920.len() - 1] {
+            assert!(
+                matches!(
+                    VideoControl::from_bytes(&C920[..len]),
+                    Err(Error::InvalidDescriptor)
+                ),
+                "{len}"
+            );
+        }
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let header = &C920[..13];
+        let mut short = header.to_vec();
+        short[0] = 0x0e;
+        assert!(matches!(
+            VideoControl::parse([short.as_slice()]),
+            Err(Error::InvalidDescriptor)
+        ));
+
+        let mut zero = C920.to_vec();
+        zero[13] = 0x00;
+        assert!(matches!(
+            VideoControl::from_bytes(&zero),
+            Err(Error::InvalidDescriptor)
+        ));
+    }
+
+    #[test]
+    fn missing_header() {
+        assert!(matches!(
+            VideoControl::from_bytes(&C920[13..]),
+            Err(Error::InvalidDescriptor)
+        ));
+        assert!(matches!(
+            VideoControl::from_bytes(&[]),
+            Err(Error::InvalidDescriptor)
+        ));
+    }
+
+    #[test]
+    fn control_size_past_end() {
+        let mut blob = C920.to_vec();
+        // bControlSize of the camera terminal
+        blob[13 + 14] = 0x05;
+        assert!(matches!(
+            VideoControl::from_bytes(&blob),
+            Err(Error::InvalidDescriptor)
+        ));
+
+        let mut blob = C920.to_vec();
+        // bControlSize of the processing unit, leaving no room for iProcessing
+        blob[13 + 18 + 7] = 0x03;
+        assert!(matches!(
+            VideoControl::from_bytes(&blob),
+            Err(Error::InvalidDescriptor)
+        ));
+    }
+
+    fn split(blob: &[u8]) -> Vec<&[u8]> {
+        let mut descriptors = vec![];
+        let mut rest = blob;
+        while !rest.is_empty() {
+            let (d, r) = rest.split_at(rest[0] as usize);
+            descriptors.push(d);
+            rest = r;
+        }
+        descriptors
+    }
+
+    #[test]
+    fn lenient_skips_broken_units() {
+        let mut descriptors = split(C920);
+        // Processing unit cut off after bControlSize
+        let pu = &C920[31..39];
+        let mut truncated = pu.to_vec();
+        truncated[0] = truncated.len() as u8;
+        descriptors[2] = &truncated;
+        // Vendor-specific subtype
+        let vendor = [0x06, 0x24, 0xf0, 0x07, 0x01, 0x02];
+        descriptors.insert(3, &vendor);
+        // bLength past the end
+        descriptors.push(&[0x09, 0x24, 0x03]);
+
+        assert!(matches!(
+            VideoControl::parse(descriptors.iter().copied()),
+            Err(Error::InvalidDescriptor)
+        ));
+
+        let vc = VideoControl::parse_lenient(descriptors.iter().copied());
+        assert_eq!(vc.header.bcd_uvc, 0x0100);
+        assert_eq!(vc.camera_terminal().unwrap().id, 1);
+        assert!(vc.processing_units.is_empty());
+        assert_eq!(vc.extension_units.len(), 1);
+        assert_eq!(vc.output_terminals.len(), 1);
+
+        assert_eq!(
+            first_unit_ids(descriptors.iter().copied()),
+            (Some(1), Some(3))
+        );
+    }
+
+    #[test]
+    fn lenient_without_header() {
+        let vc = VideoControl::parse_lenient(split(&C920[13..]));
+        assert_eq!(vc.header.interface_numbers, []);
+        assert_eq!(vc.processing_unit().unwrap().id, 3);
+        assert_eq!(first_unit_ids(split(&[])), (None, None));
+    }
+}
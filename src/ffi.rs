@@ -52,71 +52,1340 @@ type ErrorCode = i32;
 pub const ERROR_CODE_SUCCESS: ErrorCode = 0;
 pub const ERROR_CODE_UNKNOWN: ErrorCode = -1;
 
+type PowerLineFrequency = u8;
+
+pub const POWER_LINE_FREQUENCY_DISABLED: PowerLineFrequency = 0;
+pub const POWER_LINE_FREQUENCY_50HZ: PowerLineFrequency = 1;
+pub const POWER_LINE_FREQUENCY_60HZ: PowerLineFrequency = 2;
+pub const POWER_LINE_FREQUENCY_AUTO: PowerLineFrequency = 3;
+
+type TransferErrors = u32;
+
+pub const TRANSFER_ERROR_CANCELLED: TransferErrors = 1 << 0;
+pub const TRANSFER_ERROR_STALL: TransferErrors = 1 << 1;
+pub const TRANSFER_ERROR_DISCONNECTED: TransferErrors = 1 << 2;
+pub const TRANSFER_ERROR_FAULT: TransferErrors = 1 << 3;
+pub const TRANSFER_ERROR_UNKNOWN: TransferErrors = 1 << 4;
+
+type Speed = u8;
+
+pub const SPEED_UNKNOWN: Speed = 0;
+pub const SPEED_LOW: Speed = 1;
+pub const SPEED_FULL: Speed = 2;
+pub const SPEED_HIGH: Speed = 3;
+pub const SPEED_SUPER: Speed = 4;
+pub const SPEED_SUPER_PLUS: Speed = 5;
+
 #[repr(transparent)]
 pub struct DeviceInfoList(*mut c_void);
 opaque_type!(DeviceInfoList => Vec<crate::DeviceInfo>);
 
-#[repr(transparent)]
-pub struct Device(*mut c_void);
-opaque_type!(Device => crate::Device);
+#[cfg(unix)]
+type DeviceInner = crate::unix::Device<Box<dyn crate::unix::Transport + Send + Sync>>;
+#[cfg(windows)]
+type DeviceInner = crate::Device;
+
+#[repr(transparent)]
+pub struct Device(*mut c_void);
+opaque_type!(Device => DeviceInner);
+
+#[cfg(unix)]
+impl From<crate::Device> for Device {
+    fn from(device: crate::Device) -> Self {
+        device.boxed().into()
+    }
+}
+
+pub type ButtonCallback =
+    unsafe extern "C" fn(user_data: *mut c_void, interface: u8, pressed: bool);
+
+/// `location_id` is only known on macOS and is 0 elsewhere.
+///
+/// `info` is a list holding only the device that arrived or left and is valid until the callback
+/// returns. Pass it with index 0 to the `uvc_control_info_*` functions, e.g. to tell identical
+/// cameras apart by port or to `uvc_control_info_open` the one that arrived.
+pub type HotplugCallback = unsafe extern "C" fn(
+    user_data: *mut c_void,
+    arrived: bool,
+    vendor_id: u16,
+    product_id: u16,
+    location_id: u32,
+    info: &DeviceInfoList,
+);
+
+#[repr(transparent)]
+pub struct ButtonListener(*mut c_void);
+#[cfg(unix)]
+opaque_type!(ButtonListener => Listener);
+
+#[repr(transparent)]
+pub struct HotplugListener(*mut c_void);
+#[cfg(unix)]
+opaque_type!(HotplugListener => Listener);
+
+#[cfg(unix)]
+pub struct Listener {
+    stopper: crate::unix::Stopper,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(unix)]
+impl Drop for Listener {
+    fn drop(&mut self) {
+        self.stopper.stop();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// # Safety
+///
+/// `p_list` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_enumerate(p_list: *mut DeviceInfoList) -> ErrorCode {
+    let Ok(list) = crate::DeviceInfo::enumerate() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ptr::write(p_list, list.into());
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `callback` is called with `user_data` from another thread until the listener is dropped, so
+/// `user_data` must stay valid and usable from that thread until then. `p_listener` must be valid
+/// for writes.
+#[allow(unused_mut, unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_watch(
+    callback: HotplugCallback,
+    user_data: *mut c_void,
+    p_listener: *mut HotplugListener,
+) -> ErrorCode {
+    let mut r = ERROR_CODE_UNKNOWN;
+
+    #[cfg(unix)]
+    if let Ok(watcher) = crate::unix::DeviceWatcher::new() {
+        let stopper = watcher.stopper();
+        let user_data = user_data as usize;
+        let thread = std::thread::spawn(move || {
+            for event in watcher {
+                let (arrived, info) = match &event {
+                    crate::unix::DeviceEvent::Arrived(info) => (true, info),
+                    crate::unix::DeviceEvent::Left(info) => (false, info),
+                };
+                #[cfg(target_os = "macos")]
+                let location_id = info.location_id();
+                #[cfg(not(target_os = "macos"))]
+                let location_id = 0;
+                let list: DeviceInfoList = vec![info.clone()].into();
+                callback(
+                    user_data as _,
+                    arrived,
+                    info.vendor_id(),
+                    info.product_id(),
+                    location_id,
+                    &list,
+                );
+            }
+        });
+        let listener = Listener {
+            stopper,
+            thread: Some(thread),
+        };
+        ptr::write(p_listener, listener.into());
+        r = ERROR_CODE_SUCCESS;
+    }
+
+    r
+}
+
+/// Stops the watcher thread; the callback is not called after this returns.
+#[no_mangle]
+pub extern "C" fn uvc_control_hotplug_listener_drop(listener: HotplugListener) {
+    let _ = listener;
+}
+
+#[no_mangle]
+pub extern "C" fn uvc_control_info_list_drop(list: DeviceInfoList) {
+    let _ = list;
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_list_len(list: &DeviceInfoList) -> usize {
+    list.len()
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_product_id(list: &DeviceInfoList, index: usize) -> u16 {
+    list[index].product_id()
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_vendor_id(list: &DeviceInfoList, index: usize) -> u16 {
+    list[index].vendor_id()
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `data_ptr` must be null or valid for writes of `data_len`
+/// bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_serial_number(
+    list: &DeviceInfoList,
+    index: usize,
+    data_ptr: *mut c_char,
+    data_len: usize,
+) -> ErrorCode {
+    #[cfg(unix)]
+    {
+        write_str(list[index].serial_number(), data_ptr, data_len)
+    }
+
+    #[cfg(not(unix))]
+    ERROR_CODE_UNKNOWN
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `data_ptr` must be null or valid for writes of `data_len`
+/// bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_manufacturer_string(
+    list: &DeviceInfoList,
+    index: usize,
+    data_ptr: *mut c_char,
+    data_len: usize,
+) -> ErrorCode {
+    #[cfg(unix)]
+    {
+        write_str(list[index].manufacturer_string(), data_ptr, data_len)
+    }
+
+    #[cfg(not(unix))]
+    ERROR_CODE_UNKNOWN
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_bus_number(list: &DeviceInfoList, index: usize) -> u8 {
+    #[cfg(unix)]
+    {
+        list[index].bus_number()
+    }
+
+    #[cfg(not(unix))]
+    0
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_device_address(
+    list: &DeviceInfoList,
+    index: usize,
+) -> u8 {
+    #[cfg(unix)]
+    {
+        list[index].device_address()
+    }
+
+    #[cfg(not(unix))]
+    0
+}
+
+/// Writes up to `data_len` port numbers and stores how many the chain has in `len`.
+///
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `data_ptr` must be null or valid for writes of `data_len`
+/// bytes, and `len` valid for writes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_port_chain(
+    list: &DeviceInfoList,
+    index: usize,
+    data_ptr: *mut u8,
+    data_len: usize,
+    len: &mut usize,
+) -> ErrorCode {
+    #[cfg(unix)]
+    {
+        let port_chain = list[index].port_chain();
+        if !data_ptr.is_null() && data_len != 0 {
+            ptr::copy(
+                port_chain.as_ptr(),
+                data_ptr,
+                port_chain.len().min(data_len),
+            );
+        }
+        *len = port_chain.len();
+        ERROR_CODE_SUCCESS
+    }
+
+    #[cfg(not(unix))]
+    ERROR_CODE_UNKNOWN
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_speed(list: &DeviceInfoList, index: usize) -> Speed {
+    #[cfg(unix)]
+    {
+        match list[index].speed() {
+            Some(nusb::Speed::Low) => SPEED_LOW,
+            Some(nusb::Speed::Full) => SPEED_FULL,
+            Some(nusb::Speed::High) => SPEED_HIGH,
+            Some(nusb::Speed::Super) => SPEED_SUPER,
+            Some(nusb::Speed::SuperPlus) => SPEED_SUPER_PLUS,
+            _ => SPEED_UNKNOWN,
+        }
+    }
+
+    #[cfg(not(unix))]
+    SPEED_UNKNOWN
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_device_version(
+    list: &DeviceInfoList,
+    index: usize,
+) -> u16 {
+    #[cfg(unix)]
+    {
+        list[index].device_version()
+    }
+
+    #[cfg(not(unix))]
+    0
+}
+
+/// Copies `s` as a NUL-terminated string, truncating it to fit.
+///
+/// # Safety
+///
+/// `data_ptr` must be null or valid for writes of `data_len` bytes.
+#[cfg(unix)]
+unsafe fn write_str(s: Option<&str>, data_ptr: *mut c_char, data_len: usize) -> ErrorCode {
+    let Some(s) = s else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    if data_ptr.is_null() || data_len == 0 {
+        return ERROR_CODE_UNKNOWN;
+    }
+    let len = s.len().min(data_len - 1);
+    ptr::copy(s.as_ptr() as *const c_char, data_ptr, len);
+    *data_ptr.add(len) = 0;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `p_device` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_open(
+    list: &DeviceInfoList,
+    index: usize,
+    p_device: *mut Device,
+) -> ErrorCode {
+    let Ok(device) = list[index].open() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ptr::write(p_device, device.into());
+    ERROR_CODE_SUCCESS
+}
+
+/// `retryable` is a mask of `TRANSFER_ERROR_*` flags; a timed out transfer fails as cancelled.
+///
+/// # Safety
+///
+/// `list` must come from `uvc_control_enumerate` or a `HotplugCallback` and still be valid, and
+/// `index` must be less than its length. `p_device` must be valid for writes.
+#[allow(unused_mut, unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_info_open_with(
+    list: &DeviceInfoList,
+    index: usize,
+    timeout_ms: u32,
+    retries: u32,
+    backoff_ms: u32,
+    retryable: TransferErrors,
+    p_device: *mut Device,
+) -> ErrorCode {
+    let mut r = ERROR_CODE_UNKNOWN;
+
+    #[cfg(unix)]
+    {
+        let policy = transfer_policy(timeout_ms, retries, backoff_ms, retryable);
+        if let Ok(device) = list[index].open_with(policy) {
+            ptr::write(p_device, device.into());
+            r = ERROR_CODE_SUCCESS;
+        }
+    }
+
+    r
+}
+
+/// `retryable` is a mask of `TRANSFER_ERROR_*` flags; a timed out transfer fails as cancelled.
+///
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`, and must not be in use
+/// on another thread during the call.
+#[allow(unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_set_policy(
+    device: &mut Device,
+    timeout_ms: u32,
+    retries: u32,
+    backoff_ms: u32,
+    retryable: TransferErrors,
+) -> ErrorCode {
+    #[cfg(unix)]
+    {
+        device.set_policy(transfer_policy(timeout_ms, retries, backoff_ms, retryable));
+        ERROR_CODE_SUCCESS
+    }
+
+    #[cfg(not(unix))]
+    ERROR_CODE_UNKNOWN
+}
+
+#[cfg(unix)]
+fn transfer_policy(
+    timeout_ms: u32,
+    retries: u32,
+    backoff_ms: u32,
+    retryable: TransferErrors,
+) -> crate::unix::TransferPolicy {
+    use nusb::transfer::TransferError;
+
+    crate::unix::TransferPolicy {
+        timeout: std::time::Duration::from_millis(timeout_ms as _),
+        retries,
+        backoff: std::time::Duration::from_millis(backoff_ms as _),
+        retryable: [
+            (TRANSFER_ERROR_CANCELLED, TransferError::Cancelled),
+            (TRANSFER_ERROR_STALL, TransferError::Stall),
+            (TRANSFER_ERROR_DISCONNECTED, TransferError::Disconnected),
+            (TRANSFER_ERROR_FAULT, TransferError::Fault),
+            (TRANSFER_ERROR_UNKNOWN, TransferError::Unknown),
+        ]
+        .into_iter()
+        .filter(|&(flag, _)| retryable & flag != 0)
+        .map(|(_, err)| err)
+        .collect(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn uvc_control_device_drop(device: Device) {
+    let _ = device;
+}
+
+// Zoom Abs
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_abs_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.zoom_abs_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_abs(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.zoom_abs() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_abs_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.zoom_abs_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Zoom Rel
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_rel_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.zoom_rel_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_rel(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.zoom_rel() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_rel_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.zoom_rel_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Zoom Rel Speed
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_rel_speed_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.zoom_rel_speed_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_zoom_rel_move(
+    device: &Device,
+    direction: i32,
+    speed: i32,
+) -> ErrorCode {
+    let Ok(_) = device.zoom_rel_move(direction, speed) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Pan Abs
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_abs_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.pan_abs_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_abs(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.pan_abs() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_abs_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.pan_abs_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Pan Rel
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_rel_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.pan_rel_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_rel(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.pan_rel() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_rel_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.pan_rel_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Pan Rel Speed
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_rel_speed_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.pan_rel_speed_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_rel_move(
+    device: &Device,
+    direction: i32,
+    speed: i32,
+) -> ErrorCode {
+    let Ok(_) = device.pan_rel_move(direction, speed) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Tilt Abs
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_abs_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.tilt_abs_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_abs(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.tilt_abs() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_abs_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.tilt_abs_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Tilt Rel
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_rel_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.tilt_rel_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_rel(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.tilt_rel() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_rel_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.tilt_rel_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Tilt Rel Speed
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_rel_speed_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.tilt_rel_speed_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_tilt_rel_move(
+    device: &Device,
+    direction: i32,
+    speed: i32,
+) -> ErrorCode {
+    let Ok(_) = device.tilt_rel_move(direction, speed) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Pan Tilt
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `pan` and `tilt` must
+/// be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_tilt_abs(
+    device: &Device,
+    pan: &mut i32,
+    tilt: &mut i32,
+) -> ErrorCode {
+    let Ok((pan_r, tilt_r)) = device.pan_tilt_abs() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *pan = pan_r;
+    *tilt = tilt_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_tilt_abs_set(
+    device: &Device,
+    pan: i32,
+    tilt: i32,
+) -> ErrorCode {
+    let Ok(_) = device.pan_tilt_abs_set(pan, tilt) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_pan_tilt_rel_set(
+    device: &Device,
+    pan_direction: i32,
+    pan_speed: i32,
+    tilt_direction: i32,
+    tilt_speed: i32,
+) -> ErrorCode {
+    let Ok(_) = device.pan_tilt_rel_set(pan_direction, pan_speed, tilt_direction, tilt_speed)
+    else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Focus Abs
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_abs_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.focus_abs_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_abs(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.focus_abs() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_abs_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.focus_abs_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Focus Rel
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_rel_speed_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.focus_rel_speed_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_rel(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.focus_rel() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_rel_set(
+    device: &Device,
+    direction: i32,
+    speed: i32,
+) -> ErrorCode {
+    let Ok(_) = device.focus_rel_set(direction, speed) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Focus Auto
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_auto(
+    device: &Device,
+    cur: &mut bool,
+) -> ErrorCode {
+    let Ok(cur_r) = device.focus_auto() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_focus_auto_set(
+    device: &Device,
+    value: bool,
+) -> ErrorCode {
+    let Ok(_) = device.focus_auto_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Privacy
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_privacy(device: &Device, cur: &mut bool) -> ErrorCode {
+    let Ok(cur_r) = device.privacy() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_privacy_set(device: &Device, value: bool) -> ErrorCode {
+    let Ok(_) = device.privacy_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Brightness
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_brightness_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.brightness_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_brightness(
+    device: &Device,
+    cur: &mut i32,
+) -> ErrorCode {
+    let Ok(cur_r) = device.brightness() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_brightness_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.brightness_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Contrast
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_contrast_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.contrast_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_contrast(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.contrast() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_contrast_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.contrast_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
 
+// Hue
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_enumerate(p_list: *mut DeviceInfoList) -> ErrorCode {
-    let Ok(list) = crate::DeviceInfo::enumerate() else {
+pub unsafe extern "C" fn uvc_control_device_hue_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.hue_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
-    ptr::write(p_list, list.into());
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub extern "C" fn uvc_control_info_list_drop(list: DeviceInfoList) {
-    let _ = list;
+pub unsafe extern "C" fn uvc_control_device_hue(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.hue() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_info_list_len(list: &DeviceInfoList) -> usize {
-    list.len()
+pub unsafe extern "C" fn uvc_control_device_hue_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.hue_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
 }
 
+// Saturation
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_info_product_id(list: &DeviceInfoList, index: usize) -> u16 {
-    list[index].product_id()
+pub unsafe extern "C" fn uvc_control_device_saturation_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.saturation_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_info_vendor_id(list: &DeviceInfoList, index: usize) -> u16 {
-    list[index].vendor_id()
+pub unsafe extern "C" fn uvc_control_device_saturation(
+    device: &Device,
+    cur: &mut i32,
+) -> ErrorCode {
+    let Ok(cur_r) = device.saturation() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_info_open(
-    list: &DeviceInfoList,
-    index: usize,
-    p_device: *mut Device,
+pub unsafe extern "C" fn uvc_control_device_saturation_set(
+    device: &Device,
+    value: i32,
 ) -> ErrorCode {
-    let Ok(device) = list[index].open() else {
+    let Ok(_) = device.saturation_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
-    ptr::write(p_device, device.into());
     ERROR_CODE_SUCCESS
 }
 
+// Sharpness
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub extern "C" fn uvc_control_device_drop(device: Device) {
-    let _ = device;
+pub unsafe extern "C" fn uvc_control_device_sharpness_caps(
+    device: &Device,
+    min: &mut i32,
+    max: &mut i32,
+    res: &mut i32,
+    def: &mut i32,
+) -> ErrorCode {
+    let Ok(caps) = device.sharpness_caps() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *min = caps.min;
+    *max = caps.max;
+    *res = caps.res;
+    *def = caps.def;
+    ERROR_CODE_SUCCESS
 }
 
-// Zoom Abs
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_zoom_abs_caps(
+pub unsafe extern "C" fn uvc_control_device_sharpness(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.sharpness() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_sharpness_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.sharpness_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Gamma
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_gamma_caps(
     device: &Device,
     min: &mut i32,
     max: &mut i32,
     res: &mut i32,
     def: &mut i32,
 ) -> ErrorCode {
-    let Ok(caps) = device.zoom_abs_caps() else {
+    let Ok(caps) = device.gamma_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
     *min = caps.min;
@@ -126,33 +1395,44 @@ pub unsafe extern "C" fn uvc_control_device_zoom_abs_caps(
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_zoom_abs(device: &Device, cur: &mut i32) -> ErrorCode {
-    let Ok(cur_r) = device.zoom_abs() else {
+pub unsafe extern "C" fn uvc_control_device_gamma(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.gamma() else {
         return ERROR_CODE_UNKNOWN;
     };
     *cur = cur_r;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_zoom_abs_set(device: &Device, value: i32) -> ErrorCode {
-    let Ok(_) = device.zoom_abs_set(value) else {
+pub unsafe extern "C" fn uvc_control_device_gamma_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.gamma_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
     ERROR_CODE_SUCCESS
 }
 
-// Zoom Rel
+// Gain
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_zoom_rel_caps(
+pub unsafe extern "C" fn uvc_control_device_gain_caps(
     device: &Device,
     min: &mut i32,
     max: &mut i32,
     res: &mut i32,
     def: &mut i32,
 ) -> ErrorCode {
-    let Ok(caps) = device.zoom_rel_caps() else {
+    let Ok(caps) = device.gain_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
     *min = caps.min;
@@ -162,33 +1442,137 @@ pub unsafe extern "C" fn uvc_control_device_zoom_rel_caps(
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_zoom_rel(device: &Device, cur: &mut i32) -> ErrorCode {
-    let Ok(cur_r) = device.zoom_rel() else {
+pub unsafe extern "C" fn uvc_control_device_gain(device: &Device, cur: &mut i32) -> ErrorCode {
+    let Ok(cur_r) = device.gain() else {
         return ERROR_CODE_UNKNOWN;
     };
     *cur = cur_r;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_zoom_rel_set(device: &Device, value: i32) -> ErrorCode {
-    let Ok(_) = device.zoom_rel_set(value) else {
+pub unsafe extern "C" fn uvc_control_device_gain_set(device: &Device, value: i32) -> ErrorCode {
+    let Ok(_) = device.gain_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
     ERROR_CODE_SUCCESS
 }
 
-// Pan Abs
+// Hue Auto
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_pan_abs_caps(
+pub unsafe extern "C" fn uvc_control_device_hue_auto(device: &Device, cur: &mut bool) -> ErrorCode {
+    let Ok(cur_r) = device.hue_auto() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_hue_auto_set(
+    device: &Device,
+    value: bool,
+) -> ErrorCode {
+    let Ok(_) = device.hue_auto_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Contrast Auto
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_contrast_auto(
+    device: &Device,
+    cur: &mut bool,
+) -> ErrorCode {
+    let Ok(cur_r) = device.contrast_auto() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_contrast_auto_set(
+    device: &Device,
+    value: bool,
+) -> ErrorCode {
+    let Ok(_) = device.contrast_auto_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Power Line Frequency
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_power_line_frequency(
+    device: &Device,
+    cur: &mut PowerLineFrequency,
+) -> ErrorCode {
+    let Ok(cur_r) = device.power_line_frequency() else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    *cur = cur_r as _;
+    ERROR_CODE_SUCCESS
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_power_line_frequency_set(
+    device: &Device,
+    value: PowerLineFrequency,
+) -> ErrorCode {
+    let Some(value) = crate::PowerLineFrequency::from_u8(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    let Ok(_) = device.power_line_frequency_set(value) else {
+        return ERROR_CODE_UNKNOWN;
+    };
+    ERROR_CODE_SUCCESS
+}
+
+// Backlight Compensation
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_backlight_compensation_caps(
     device: &Device,
     min: &mut i32,
     max: &mut i32,
     res: &mut i32,
     def: &mut i32,
 ) -> ErrorCode {
-    let Ok(caps) = device.pan_abs_caps() else {
+    let Ok(caps) = device.backlight_compensation_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
     *min = caps.min;
@@ -198,33 +1582,50 @@ pub unsafe extern "C" fn uvc_control_device_pan_abs_caps(
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_pan_abs(device: &Device, cur: &mut i32) -> ErrorCode {
-    let Ok(cur_r) = device.pan_abs() else {
+pub unsafe extern "C" fn uvc_control_device_backlight_compensation(
+    device: &Device,
+    cur: &mut i32,
+) -> ErrorCode {
+    let Ok(cur_r) = device.backlight_compensation() else {
         return ERROR_CODE_UNKNOWN;
     };
     *cur = cur_r;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_pan_abs_set(device: &Device, value: i32) -> ErrorCode {
-    let Ok(_) = device.pan_abs_set(value) else {
+pub unsafe extern "C" fn uvc_control_device_backlight_compensation_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.backlight_compensation_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
     ERROR_CODE_SUCCESS
 }
 
-// Pan Rel
+// Digital Multiplier
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_pan_rel_caps(
+pub unsafe extern "C" fn uvc_control_device_digital_multiplier_caps(
     device: &Device,
     min: &mut i32,
     max: &mut i32,
     res: &mut i32,
     def: &mut i32,
 ) -> ErrorCode {
-    let Ok(caps) = device.pan_rel_caps() else {
+    let Ok(caps) = device.digital_multiplier_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
     *min = caps.min;
@@ -234,33 +1635,50 @@ pub unsafe extern "C" fn uvc_control_device_pan_rel_caps(
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_pan_rel(device: &Device, cur: &mut i32) -> ErrorCode {
-    let Ok(cur_r) = device.pan_rel() else {
+pub unsafe extern "C" fn uvc_control_device_digital_multiplier(
+    device: &Device,
+    cur: &mut i32,
+) -> ErrorCode {
+    let Ok(cur_r) = device.digital_multiplier() else {
         return ERROR_CODE_UNKNOWN;
     };
     *cur = cur_r;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_pan_rel_set(device: &Device, value: i32) -> ErrorCode {
-    let Ok(_) = device.pan_rel_set(value) else {
+pub unsafe extern "C" fn uvc_control_device_digital_multiplier_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.digital_multiplier_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
     ERROR_CODE_SUCCESS
 }
 
-// Tilt Abs
+// Digital Multiplier Limit
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_tilt_abs_caps(
+pub unsafe extern "C" fn uvc_control_device_digital_multiplier_limit_caps(
     device: &Device,
     min: &mut i32,
     max: &mut i32,
     res: &mut i32,
     def: &mut i32,
 ) -> ErrorCode {
-    let Ok(caps) = device.tilt_abs_caps() else {
+    let Ok(caps) = device.digital_multiplier_limit_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
     *min = caps.min;
@@ -270,33 +1688,50 @@ pub unsafe extern "C" fn uvc_control_device_tilt_abs_caps(
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_tilt_abs(device: &Device, cur: &mut i32) -> ErrorCode {
-    let Ok(cur_r) = device.tilt_abs() else {
+pub unsafe extern "C" fn uvc_control_device_digital_multiplier_limit(
+    device: &Device,
+    cur: &mut i32,
+) -> ErrorCode {
+    let Ok(cur_r) = device.digital_multiplier_limit() else {
         return ERROR_CODE_UNKNOWN;
     };
     *cur = cur_r;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_tilt_abs_set(device: &Device, value: i32) -> ErrorCode {
-    let Ok(_) = device.tilt_abs_set(value) else {
+pub unsafe extern "C" fn uvc_control_device_digital_multiplier_limit_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.digital_multiplier_limit_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
     ERROR_CODE_SUCCESS
 }
 
-// Tilt Rel
+// Zoom Effective
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `min`, `max`, `res` and
+/// `def` must be valid for writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_tilt_rel_caps(
+pub unsafe extern "C" fn uvc_control_device_zoom_effective_caps(
     device: &Device,
     min: &mut i32,
     max: &mut i32,
     res: &mut i32,
     def: &mut i32,
 ) -> ErrorCode {
-    let Ok(caps) = device.tilt_rel_caps() else {
+    let Ok(caps) = device.zoom_effective_caps() else {
         return ERROR_CODE_UNKNOWN;
     };
     *min = caps.min;
@@ -306,23 +1741,89 @@ pub unsafe extern "C" fn uvc_control_device_tilt_rel_caps(
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `cur` must be valid for
+/// writes.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_tilt_rel(device: &Device, cur: &mut i32) -> ErrorCode {
-    let Ok(cur_r) = device.tilt_rel() else {
+pub unsafe extern "C" fn uvc_control_device_zoom_effective(
+    device: &Device,
+    cur: &mut i32,
+) -> ErrorCode {
+    let Ok(cur_r) = device.zoom_effective() else {
         return ERROR_CODE_UNKNOWN;
     };
     *cur = cur_r;
     ERROR_CODE_SUCCESS
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[no_mangle]
-pub unsafe extern "C" fn uvc_control_device_tilt_rel_set(device: &Device, value: i32) -> ErrorCode {
-    let Ok(_) = device.tilt_rel_set(value) else {
+pub unsafe extern "C" fn uvc_control_device_zoom_effective_set(
+    device: &Device,
+    value: i32,
+) -> ErrorCode {
+    let Ok(_) = device.zoom_effective_set(value) else {
         return ERROR_CODE_UNKNOWN;
     };
     ERROR_CODE_SUCCESS
 }
 
+// Button
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `callback` is called
+/// with `user_data` from another thread until the listener is dropped, so `user_data` must stay
+/// valid and usable from that thread until then. `p_listener` must be valid for writes.
+#[allow(unused_mut, unused_variables)]
+#[no_mangle]
+pub unsafe extern "C" fn uvc_control_device_listen_button(
+    device: &Device,
+    callback: ButtonCallback,
+    user_data: *mut c_void,
+    p_listener: *mut ButtonListener,
+) -> ErrorCode {
+    let mut r = ERROR_CODE_UNKNOWN;
+
+    #[cfg(unix)]
+    if let Ok(events) = device.events() {
+        let stopper = events.stopper();
+        let user_data = user_data as usize;
+        let thread = std::thread::spawn(move || {
+            for event in events {
+                match event {
+                    Ok(crate::unix::Event::Button { interface, pressed }) => {
+                        callback(user_data as _, interface, pressed)
+                    }
+                    Ok(_) => {}
+                    // `Events` resubmits after a failed transfer and ends on disconnect.
+                    Err(_) => continue,
+                }
+            }
+        });
+        let listener = Listener {
+            stopper,
+            thread: Some(thread),
+        };
+        ptr::write(p_listener, listener.into());
+        r = ERROR_CODE_SUCCESS;
+    }
+
+    r
+}
+
+/// Stops the listener thread; the callback is not called after this returns.
+#[no_mangle]
+pub extern "C" fn uvc_control_button_listener_drop(listener: ButtonListener) {
+    let _ = listener;
+}
+
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `data_ptr` must be
+/// valid for writes of `data_len` bytes.
 #[allow(unused_mut, unused_variables)]
 #[no_mangle]
 pub unsafe extern "C" fn uvc_control_device_unix_get(
@@ -336,7 +1837,7 @@ pub unsafe extern "C" fn uvc_control_device_unix_get(
 
     #[cfg(unix)]
     {
-        if let Ok(r_data) = device.get::<32>(crate::unix::Request::GetCur, control_code, unit) {
+        if let Ok(r_data) = device.get_raw::<32>(crate::unix::Request::GetCur, control_code, unit) {
             ptr::copy(r_data.as_ptr(), data_ptr, r_data.len().min(data_len));
             r = ERROR_CODE_SUCCESS;
         }
@@ -345,6 +1846,10 @@ pub unsafe extern "C" fn uvc_control_device_unix_get(
     r
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `data_ptr` must be
+/// valid for reads of `data_len` bytes.
 #[allow(unused_mut, unused_variables)]
 #[no_mangle]
 pub unsafe extern "C" fn uvc_control_device_unix_set(
@@ -359,7 +1864,7 @@ pub unsafe extern "C" fn uvc_control_device_unix_set(
     #[cfg(unix)]
     {
         let data = slice::from_raw_parts(data_ptr, data_len);
-        if device.set(control_code, unit, data).is_ok() {
+        if device.set_raw(control_code, unit, data).is_ok() {
             r = ERROR_CODE_SUCCESS;
         }
     }
@@ -367,6 +1872,9 @@ pub unsafe extern "C" fn uvc_control_device_unix_set(
     r
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`.
 #[allow(unused_mut, unused_variables)]
 #[no_mangle]
 pub unsafe extern "C" fn uvc_control_device_win_set(
@@ -384,6 +1892,10 @@ pub unsafe extern "C" fn uvc_control_device_win_set(
     r
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `set` must be a
+/// NUL-terminated GUID string and `data_ptr` valid for writes of `data_len` bytes.
 #[allow(unused_mut, unused_variables)]
 #[no_mangle]
 pub unsafe extern "C" fn uv_control_device_win_get_xu(
@@ -406,6 +1918,10 @@ pub unsafe extern "C" fn uv_control_device_win_get_xu(
     r
 }
 
+/// # Safety
+///
+/// `device` must be open, i.e. not yet passed to `uvc_control_device_drop`. `set` must be a
+/// NUL-terminated GUID string and `data_ptr` valid for reads of `data_len` bytes.
 #[allow(unused_mut, unused_variables)]
 #[no_mangle]
 pub unsafe extern "C" fn uvc_control_device_win_set_xu(
@@ -427,3 +1943,265 @@ pub unsafe extern "C" fn uvc_control_device_win_set_xu(
 
     r
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::{
+        codec,
+        control::ControlId,
+        descriptors::{CameraTerminal, InputTerminal, ProcessingUnit, VideoControl},
+        unix::emulator::{EmulatedControl, Emulator},
+    };
+    use nusb::transfer::TransferError;
+
+    const INF: u8 = 0;
+    const IT: u8 = 1;
+    const PU: u8 = 2;
+
+    fn device() -> (Emulator, Device) {
+        let emulator = Emulator::new(VideoControl {
+            input_terminals: vec![InputTerminal {
+                id: IT,
+                terminal_type: 0x0201,
+                assoc_terminal: 0,
+                terminal_string: 0,
+                camera: Some(CameraTerminal {
+                    objective_focal_length_min: 0,
+                    objective_focal_length_max: 0,
+                    ocular_focal_length: 0,
+                    controls: vec![0x00, 0x0a, 0x00],
+                }),
+            }],
+            processing_units: vec![ProcessingUnit {
+                id: PU,
+                source_id: IT,
+                max_multiplier: 0,
+                controls: vec![0x01, 0x05],
+                processing_string: 0,
+                video_standards: None,
+            }],
+            ..Default::default()
+        });
+        emulator
+            .control(
+                IT,
+                ControlId::ZoomAbs.selector(),
+                EmulatedControl::new(codec::ZOOM_ABS).range(&[100], &[500], &[10], &[100]),
+            )
+            .control(
+                IT,
+                ControlId::PanTiltAbs.selector(),
+                EmulatedControl::new(codec::PAN_TILT_ABS).range(
+                    &[-36000, -18000],
+                    &[36000, 18000],
+                    &[3600, 3600],
+                    &[0, 0],
+                ),
+            )
+            .control(
+                PU,
+                ControlId::Brightness.selector(),
+                EmulatedControl::new(codec::BRIGHTNESS).range(&[-64], &[64], &[1], &[0]),
+            )
+            .control(
+                PU,
+                ControlId::PowerLineFrequency.selector(),
+                EmulatedControl::new(codec::POWER_LINE_FREQUENCY).range(&[0], &[2], &[1], &[1]),
+            )
+            .control(
+                PU,
+                ControlId::HueAuto.selector(),
+                EmulatedControl::new(codec::HUE_AUTO).range(&[0], &[1], &[1], &[0]),
+            );
+        let device = emulator.device(INF).boxed().into();
+        (emulator, device)
+    }
+
+    #[test]
+    fn caps_get_set() {
+        let (emulator, device) = device();
+        let (mut min, mut max, mut res, mut def) = (0, 0, 0, 0);
+        let mut cur = 0;
+
+        unsafe {
+            assert_eq!(
+                uvc_control_device_zoom_abs_caps(&device, &mut min, &mut max, &mut res, &mut def),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!((min, max, res, def), (100, 500, 10, 100));
+            assert_eq!(
+                uvc_control_device_zoom_abs_set(&device, 250),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_zoom_abs(&device, &mut cur),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(cur, 250);
+
+            assert_eq!(
+                uvc_control_device_brightness_set(&device, -10),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_brightness(&device, &mut cur),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(cur, -10);
+        }
+        assert_eq!(
+            emulator.cur(IT, ControlId::ZoomAbs.selector()),
+            Some(vec![0xfa, 0x00])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let (_, device) = device();
+        let mut cur = 0;
+
+        unsafe {
+            assert_eq!(
+                uvc_control_device_zoom_abs_set(&device, 510),
+                ERROR_CODE_UNKNOWN
+            );
+            assert_eq!(
+                uvc_control_device_gain(&device, &mut cur),
+                ERROR_CODE_UNKNOWN
+            );
+        }
+    }
+
+    #[test]
+    fn pan_tilt() {
+        let (_, device) = device();
+        let (mut pan, mut tilt) = (0, 0);
+
+        unsafe {
+            assert_eq!(
+                uvc_control_device_pan_tilt_abs_set(&device, 3600, -7200),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_tilt_abs_set(&device, 7200),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_pan_tilt_abs(&device, &mut pan, &mut tilt),
+                ERROR_CODE_SUCCESS
+            );
+        }
+        assert_eq!((pan, tilt), (3600, 7200));
+    }
+
+    #[test]
+    fn enum_and_bool_controls() {
+        let (_, device) = device();
+        let mut frequency = POWER_LINE_FREQUENCY_DISABLED;
+        let mut auto = true;
+
+        unsafe {
+            assert_eq!(
+                uvc_control_device_power_line_frequency_set(&device, POWER_LINE_FREQUENCY_60HZ),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_power_line_frequency(&device, &mut frequency),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(frequency, POWER_LINE_FREQUENCY_60HZ);
+            assert_eq!(
+                uvc_control_device_power_line_frequency_set(&device, POWER_LINE_FREQUENCY_AUTO),
+                ERROR_CODE_UNKNOWN
+            );
+            assert_eq!(
+                uvc_control_device_power_line_frequency_set(&device, 9),
+                ERROR_CODE_UNKNOWN
+            );
+
+            assert_eq!(
+                uvc_control_device_hue_auto(&device, &mut auto),
+                ERROR_CODE_SUCCESS
+            );
+            assert!(!auto);
+            assert_eq!(
+                uvc_control_device_hue_auto_set(&device, true),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_hue_auto(&device, &mut auto),
+                ERROR_CODE_SUCCESS
+            );
+            assert!(auto);
+        }
+    }
+
+    #[test]
+    fn raw_requests() {
+        let (emulator, mut device) = device();
+        let mut data = [0; 2];
+
+        unsafe {
+            assert_eq!(
+                uvc_control_device_unix_set(
+                    &device,
+                    ControlId::ZoomAbs.selector(),
+                    IT,
+                    [0x2c, 0x01].as_ptr(),
+                    2
+                ),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_unix_get(
+                    &device,
+                    ControlId::ZoomAbs.selector(),
+                    IT,
+                    data.as_mut_ptr(),
+                    data.len()
+                ),
+                ERROR_CODE_SUCCESS
+            );
+            assert_eq!(
+                uvc_control_device_set_policy(
+                    &mut device,
+                    100,
+                    2,
+                    0,
+                    TRANSFER_ERROR_CANCELLED | TRANSFER_ERROR_FAULT
+                ),
+                ERROR_CODE_SUCCESS
+            );
+        }
+        assert_eq!(data, [0x2c, 0x01]);
+        assert_eq!(
+            emulator.cur(IT, ControlId::ZoomAbs.selector()),
+            Some(vec![0x2c, 0x01])
+        );
+        assert_eq!(device.policy().retries, 2);
+        assert_eq!(
+            device.policy().retryable,
+            [TransferError::Cancelled, TransferError::Fault]
+        );
+    }
+
+    #[test]
+    fn listen_button_without_status_endpoint() {
+        unsafe extern "C" fn callback(_: *mut c_void, _: u8, _: bool) {}
+
+        let (_, device) = device();
+        let mut listener = mem::MaybeUninit::<ButtonListener>::uninit();
+        unsafe {
+            assert_eq!(
+                uvc_control_device_listen_button(
+                    &device,
+                    callback,
+                    ptr::null_mut(),
+                    listener.as_mut_ptr()
+                ),
+                ERROR_CODE_UNKNOWN
+            );
+        }
+    }
+}
@@ -1,8 +1,15 @@
+pub use control::{
+    AeMode, ControlId, ControlInfo, FocusSimpleRange, PowerLineFrequency, RequestErrorCode,
+    ScanningMode, Value,
+};
 #[cfg(unix)]
 pub use unix::{Device, DeviceInfo};
 #[cfg(windows)]
 pub use windows::{Device, DeviceInfo};
 
+pub mod codec;
+pub mod control;
+pub mod descriptors;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 #[cfg(unix)]
@@ -12,15 +19,30 @@ mod windows;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("invalid descriptor")]
+    InvalidDescriptor,
+    #[error("unknown control")]
+    UnknownControl,
+    #[error("invalid value")]
+    InvalidValue,
     #[cfg(unix)]
     #[error("{0}")]
     Usb(#[from] nusb::Error),
     #[cfg(unix)]
+    #[error("device not found")]
+    DeviceNotFound,
+    #[cfg(unix)]
     #[error("interface not found")]
     InterfaceNotFound,
     #[cfg(unix)]
+    #[error("endpoint not found")]
+    EndpointNotFound,
+    #[cfg(unix)]
     #[error("{0}")]
     UbsTransfer(#[from] nusb::transfer::TransferError),
+    #[cfg(unix)]
+    #[error("{0}")]
+    Uvc(RequestErrorCode),
     #[cfg(windows)]
     #[error("{0}")]
     Win(#[from] ::windows::core::Error),
@@ -29,10 +51,16 @@ pub enum Error {
     Io(#[from] std::io::Error),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Caps {
     pub min: i32,
     pub max: i32,
     pub res: i32,
     pub def: i32,
 }
+
+impl Caps {
+    pub fn contains(&self, value: i32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
@@ -1,7 +1,33 @@
-use crate::{Caps, Error};
-use nusb::transfer;
-use std::{mem, time::Duration};
+use crate::{
+    control::{
+        AeMode, ControlId, ControlInfo, EffectiveZoom, FocusSimpleRange, PowerLineFrequency,
+        RequestErrorCode, ScanningMode, UnitKind, Value,
+    },
+    descriptors::{self, SupportedControls, VideoControl},
+    Caps, Error,
+};
+use nusb::transfer::{self, TransferError};
+use std::{sync::Mutex, thread, time::Duration};
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod emulator;
+pub mod events;
+pub mod mock;
+pub mod resilient;
+mod stop;
+#[cfg(test)]
+mod tests;
+pub mod watcher;
+
+#[cfg(feature = "async")]
+pub use asynchronous::AsyncDevice;
+pub use events::{Attribute, Event, Events};
+pub use resilient::ResilientDevice;
+pub use stop::Stopper;
+pub use watcher::{DeviceEvent, DeviceWatcher};
+
+#[derive(Clone)]
 pub struct DeviceInfo {
     inner: nusb::DeviceInfo,
 }
@@ -10,10 +36,7 @@ impl DeviceInfo {
     pub fn enumerate() -> Result<Vec<Self>, Error> {
         Ok(nusb::list_devices()?
             .filter_map(|inner| {
-                if inner
-                    .interfaces()
-                    .any(|inf| inf.class() == UsbClass::Video as _)
-                {
+                if Self::is_video(&inner) {
                     Some(DeviceInfo { inner })
                 } else {
                     None
@@ -22,6 +45,26 @@ impl DeviceInfo {
             .collect())
     }
 
+    /// Whether both describe the same physical camera, by serial number when it has one and by
+    /// port otherwise.
+    fn same_device(&self, other: &DeviceInfo) -> bool {
+        if self.inner.vendor_id() != other.inner.vendor_id()
+            || self.inner.product_id() != other.inner.product_id()
+        {
+            return false;
+        }
+        match (self.inner.serial_number(), other.inner.serial_number()) {
+            (Some(a), Some(b)) if !a.is_empty() => a == b,
+            _ => self.bus_number() == other.bus_number() && self.port_chain() == other.port_chain(),
+        }
+    }
+
+    fn is_video(inner: &nusb::DeviceInfo) -> bool {
+        inner
+            .interfaces()
+            .any(|inf| inf.class() == UsbClass::Video as _)
+    }
+
     pub fn product_string(&self) -> Option<&str> {
         self.inner.product_string()
     }
@@ -34,11 +77,64 @@ impl DeviceInfo {
         self.inner.vendor_id()
     }
 
+    #[cfg(target_os = "macos")]
     pub fn location_id(&self) -> u32 {
         self.inner.location_id()
     }
 
+    pub fn serial_number(&self) -> Option<&str> {
+        self.inner.serial_number()
+    }
+
+    pub fn manufacturer_string(&self) -> Option<&str> {
+        self.inner.manufacturer_string()
+    }
+
+    pub fn bus_number(&self) -> u8 {
+        self.inner.bus_number()
+    }
+
+    pub fn device_address(&self) -> u8 {
+        self.inner.device_address()
+    }
+
+    /// The hub port numbers from the root hub down to the device, one nibble each of the
+    /// location id.
+    #[cfg(target_os = "macos")]
+    pub fn port_chain(&self) -> Vec<u8> {
+        let location_id = self.location_id();
+        (0..6)
+            .rev()
+            .map(|i| ((location_id >> (i * 4)) & 0xf) as u8)
+            .take_while(|&port| port != 0)
+            .collect()
+    }
+
+    /// The hub port numbers from the root hub down to the device, from the sysfs name, e.g.
+    /// `1-2.4`.
+    #[cfg(not(target_os = "macos"))]
+    pub fn port_chain(&self) -> Vec<u8> {
+        let name = self.inner.sysfs_path().file_name().unwrap_or_default();
+        name.to_string_lossy()
+            .split_once('-')
+            .map(|(_, ports)| ports.split('.').filter_map(|p| p.parse().ok()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn speed(&self) -> Option<nusb::Speed> {
+        self.inner.speed()
+    }
+
+    /// `bcdDevice`.
+    pub fn device_version(&self) -> u16 {
+        self.inner.device_version()
+    }
+
     pub fn open(&self) -> Result<Device, Error> {
+        self.open_with(TransferPolicy::default())
+    }
+
+    pub fn open_with(&self, policy: TransferPolicy) -> Result<Device, Error> {
         let inner = self.inner.open()?;
         let Some(inf) = inner
             .configurations()
@@ -50,232 +146,1000 @@ impl DeviceInfo {
             return Err(Error::InterfaceNotFound);
         };
 
-        let mut it_unit = 0;
-        let mut pu_unit = 0;
+        let inf_no = inf.interface_number();
+        let descriptors = VideoControl::parse_lenient(inf.descriptors());
+        let (it_unit, pu_unit) = descriptors::first_unit_ids(inf.descriptors());
+        let status_ep = inf
+            .endpoints()
+            .find(|ep| {
+                ep.transfer_type() == transfer::EndpointType::Interrupt
+                    && ep.direction() == transfer::Direction::In
+            })
+            .map(|ep| (ep.address(), ep.max_packet_size()));
 
-        for d in inf.descriptors() {
-            if d[1] == DescriptorType::CSInterface as _ {
-                if d[2] == DescriptorType::VCInputTerminal as _ && it_unit == 0 {
-                    it_unit = d[3];
-                } else if d[2] == DescriptorType::VCProcessingUnit as _ && pu_unit == 0 {
-                    pu_unit = d[3];
-                }
-            }
+        let mut device = Device::with_transport(inner, inf_no, descriptors);
+        // Units whose descriptors could not be parsed are still addressed by their id.
+        if device.it_unit == 0 {
+            device.it_unit = it_unit.unwrap_or(0);
         }
+        if device.pu_unit == 0 {
+            device.pu_unit = pu_unit.unwrap_or(0);
+        }
+        device.status_ep = status_ep;
+        device.policy = policy;
+        Ok(device)
+    }
+}
 
-        Ok(Device {
-            inf_no: inf.interface_number(),
-            it_unit,
-            pu_unit,
-            inner,
-        })
+pub trait Transport {
+    fn control_in(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, TransferError>;
+
+    fn control_out(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &[u8],
+        timeout: Duration,
+    ) -> Result<usize, TransferError>;
+
+    /// Claims the interface to listen on its interrupt endpoint. Transports without a USB
+    /// device behind them have none.
+    fn claim_interface(&self, _inf_no: u8) -> Result<nusb::Interface, Error> {
+        Err(Error::InterfaceNotFound)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn control_in(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        (**self).control_in(request, value, index, data, timeout)
+    }
+
+    fn control_out(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &[u8],
+        timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        (**self).control_out(request, value, index, data, timeout)
+    }
+
+    fn claim_interface(&self, inf_no: u8) -> Result<nusb::Interface, Error> {
+        (**self).claim_interface(inf_no)
+    }
+}
+
+impl Transport for nusb::Device {
+    fn control_in(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        self.control_in_blocking(
+            transfer::Control {
+                control_type: transfer::ControlType::Class,
+                recipient: transfer::Recipient::Interface,
+                request,
+                value,
+                index,
+            },
+            data,
+            timeout,
+        )
+    }
+
+    fn control_out(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &[u8],
+        timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        self.control_out_blocking(
+            transfer::Control {
+                control_type: transfer::ControlType::Class,
+                recipient: transfer::Recipient::Interface,
+                request,
+                value,
+                index,
+            },
+            data,
+            timeout,
+        )
+    }
+
+    fn claim_interface(&self, inf_no: u8) -> Result<nusb::Interface, Error> {
+        Ok(nusb::Device::claim_interface(self, inf_no)?)
+    }
+}
+
+/// How control transfers are timed out and retried.
+#[derive(Debug, Clone)]
+pub struct TransferPolicy {
+    pub timeout: Duration,
+    /// Number of extra attempts after the first one fails with a retryable error.
+    pub retries: u32,
+    /// Waits `backoff * attempt` before each retry.
+    pub backoff: Duration,
+    pub retryable: Vec<TransferError>,
+}
+
+impl Default for TransferPolicy {
+    fn default() -> Self {
+        TransferPolicy {
+            timeout: Duration::from_secs(1),
+            retries: 0,
+            backoff: Duration::from_millis(50),
+            retryable: vec![TransferError::Stall, TransferError::Fault],
+        }
     }
 }
 
-pub struct Device {
+pub struct Device<T = nusb::Device> {
     inf_no: u8,
     it_unit: u8,
     pu_unit: u8,
-    inner: nusb::Device,
+    status_ep: Option<(u8, usize)>,
+    policy: TransferPolicy,
+    applied: Mutex<Vec<(ControlId, Value)>>,
+    descriptors: VideoControl,
+    inner: T,
 }
 
-impl Device {
-    pub fn get<const C: usize>(
+impl<T: Transport> Device<T> {
+    pub fn with_transport(inner: T, inf_no: u8, descriptors: VideoControl) -> Self {
+        let it_unit = descriptors
+            .camera_terminal()
+            .or(descriptors.input_terminals.first())
+            .map_or(0, |t| t.id);
+        let pu_unit = descriptors.processing_units.first().map_or(0, |u| u.id);
+
+        Device {
+            inf_no,
+            it_unit,
+            pu_unit,
+            status_ep: None,
+            policy: TransferPolicy::default(),
+            applied: Mutex::default(),
+            descriptors,
+            inner,
+        }
+    }
+
+    /// Erases the transport type, e.g. to hand real and emulated devices to the same code.
+    pub fn boxed(self) -> Device<Box<dyn Transport + Send + Sync>>
+    where
+        T: Send + Sync + 'static,
+    {
+        Device {
+            inf_no: self.inf_no,
+            it_unit: self.it_unit,
+            pu_unit: self.pu_unit,
+            status_ep: self.status_ep,
+            policy: self.policy,
+            applied: self.applied,
+            descriptors: self.descriptors,
+            inner: Box::new(self.inner),
+        }
+    }
+
+    pub fn descriptors(&self) -> &VideoControl {
+        &self.descriptors
+    }
+
+    pub fn policy(&self) -> &TransferPolicy {
+        &self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: TransferPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn supported_controls(&self) -> SupportedControls {
+        self.descriptors.supported_controls()
+    }
+
+    pub fn get_raw<const C: usize>(
         &self,
         req: Request,
         control_code: u8,
         unit: u8,
     ) -> Result<[u8; C], Error> {
         let mut data = [0; C];
-        let _ = self.inner.control_in_blocking(
-            transfer::Control {
-                control_type: transfer::ControlType::Class,
-                recipient: transfer::Recipient::Interface,
-                request: req as _,
-                value: (control_code as u16) << 8,
-                index: (unit as u16) << 8 | self.inf_no as u16,
-            },
+        self.request_in(req, control_code, unit, &mut data)?;
+        Ok(data)
+    }
+
+    pub fn set_raw(&self, control_code: u8, unit: u8, data: &[u8]) -> Result<(), Error> {
+        self.retry(|timeout| {
+            self.inner.control_out(
+                Request::SetCur as _,
+                (control_code as u16) << 8,
+                (unit as u16) << 8 | self.inf_no as u16,
+                data,
+                timeout,
+            )
+        })
+        .map_err(|err| self.transfer_error(err))?;
+        Ok(())
+    }
+
+    fn request_in(
+        &self,
+        req: Request,
+        control_code: u8,
+        unit: u8,
+        data: &mut [u8],
+    ) -> Result<(), Error> {
+        self.retry(|timeout| {
+            self.inner.control_in(
+                req as _,
+                (control_code as u16) << 8,
+                (unit as u16) << 8 | self.inf_no as u16,
+                data,
+                timeout,
+            )
+        })
+        .map_err(|err| self.transfer_error(err))?;
+        Ok(())
+    }
+
+    fn retry(
+        &self,
+        mut f: impl FnMut(Duration) -> Result<usize, TransferError>,
+    ) -> Result<usize, TransferError> {
+        let mut attempt = 0;
+        loop {
+            match f(self.policy.timeout) {
+                Err(err)
+                    if attempt < self.policy.retries && self.policy.retryable.contains(&err) =>
+                {
+                    attempt += 1;
+                    thread::sleep(self.policy.backoff * attempt);
+                }
+                r => return r,
+            }
+        }
+    }
+
+    /// Explains a stall with `VC_REQUEST_ERROR_CODE_CONTROL` when the device implements it.
+    fn transfer_error(&self, err: TransferError) -> Error {
+        if err != TransferError::Stall {
+            return err.into();
+        }
+
+        let mut code = [0; 1];
+        let r = self.inner.control_in(
+            Request::GetCur as _,
+            (VC_REQUEST_ERROR_CODE_CONTROL as u16) << 8,
+            self.inf_no as u16,
+            &mut code,
+            self.policy.timeout,
+        );
+        match r.ok().and_then(|_| RequestErrorCode::from_u8(code[0])) {
+            Some(code) => Error::Uvc(code),
+            None => err.into(),
+        }
+    }
+
+    fn unit(&self, kind: UnitKind) -> u8 {
+        match kind {
+            UnitKind::CameraTerminal => self.it_unit,
+            UnitKind::ProcessingUnit => self.pu_unit,
+        }
+    }
+
+    fn get_control(&self, req: Request, id: ControlId) -> Result<Vec<u8>, Error> {
+        let mut data = vec![0; id.layout().len];
+        self.request_in(req, id.selector(), self.unit(id.unit()), &mut data)?;
+        Ok(data)
+    }
+
+    /// Returns the range of every field of the control.
+    pub fn caps(&self, id: ControlId) -> Result<Vec<Caps>, Error> {
+        let layout = id.layout();
+        let min = self.get_control(Request::GetMin, id)?;
+        let max = self.get_control(Request::GetMax, id)?;
+        let res = self.get_control(Request::GetRes, id)?;
+        let def = self.get_control(Request::GetDef, id)?;
+        Ok(layout
+            .fields
+            .iter()
+            .map(|f| Caps {
+                min: f.read(&min) as _,
+                max: f.read(&max) as _,
+                res: f.read(&res) as _,
+                def: f.read(&def) as _,
+            })
+            .collect())
+    }
+
+    /// Rejects a value outside GET_MIN..=GET_MAX of a single field control. When the camera
+    /// stalls the range requests the check is skipped and SET_CUR reports the error instead.
+    fn check_range(&self, id: ControlId, value: i32) -> Result<(), Error> {
+        let range = self
+            .get_control(Request::GetMin, id)
+            .and_then(|min| Ok((min, self.get_control(Request::GetMax, id)?)));
+        let (min, max) = match range {
+            Ok(range) => range,
+            Err(Error::Uvc(_) | Error::UbsTransfer(TransferError::Stall)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let field = id.layout().fields[0];
+        if !(field.read(&min) as i32..=field.read(&max) as i32).contains(&value) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(())
+    }
+
+    pub fn info(&self, id: ControlId) -> Result<ControlInfo, Error> {
+        let mut data = [0; 1];
+        self.request_in(
+            Request::GetInfo,
+            id.selector(),
+            self.unit(id.unit()),
             &mut data,
-            Duration::from_secs(1),
         )?;
-        Ok(data)
+        Ok(ControlInfo::from_bits(data[0]))
     }
 
-    pub fn set(&self, control_code: u8, unit: u8, data: &[u8]) -> Result<(), Error> {
-        let _ = self.inner.control_out_blocking(
-            transfer::Control {
-                control_type: transfer::ControlType::Class,
-                recipient: transfer::Recipient::Interface,
-                request: Request::SetCur as _,
-                value: (control_code as u16) << 8,
-                index: (unit as u16) << 8 | self.inf_no as u16,
-            },
-            data,
-            Duration::from_secs(1),
+    /// The length of the control payload as reported by the device.
+    pub fn len(&self, id: ControlId) -> Result<u16, Error> {
+        let mut data = [0; 2];
+        self.request_in(
+            Request::GetLen,
+            id.selector(),
+            self.unit(id.unit()),
+            &mut data,
         )?;
+        Ok(u16::from_le_bytes(data))
+    }
+
+    pub fn get(&self, id: ControlId) -> Result<Value, Error> {
+        let cur = self.get_control(Request::GetCur, id)?;
+        Ok(Value(id.layout().decode(&cur)))
+    }
+
+    pub fn set(&self, id: ControlId, value: Value) -> Result<(), Error> {
+        let data = id.layout().encode(&value.0);
+        self.set_raw(id.selector(), self.unit(id.unit()), &data)?;
+        if id.is_relative() {
+            return Ok(());
+        }
+
+        let mut applied = self.applied.lock().unwrap();
+        applied.retain(|(i, _)| *i != id);
+        applied.push((id, value));
         Ok(())
     }
 
+    /// The last value successfully set on each absolute control, oldest first. Relative controls
+    /// are left out since replaying them would move the camera again.
+    pub fn applied(&self) -> Vec<(ControlId, Value)> {
+        self.applied.lock().unwrap().clone()
+    }
+
+    /// Sets each value again, e.g. the `applied` history of the same camera before it was
+    /// reopened. All of them become this device's history, including the ones the camera
+    /// rejected, so a later replay tries those again. Returns the rejections.
+    pub fn replay(&self, applied: Vec<(ControlId, Value)>) -> Vec<(ControlId, Error)> {
+        let errors = applied
+            .iter()
+            .filter_map(|(id, value)| self.set(*id, value.clone()).err().map(|err| (*id, err)))
+            .collect();
+        *self.applied.lock().unwrap() = applied;
+        errors
+    }
+
     pub fn zoom_abs_caps(&self) -> Result<Caps, Error> {
-        let min = self.get::<2>(Request::GetMin, Control::ZoomAbs as _, self.it_unit)?;
-        let max = self.get::<2>(Request::GetMax, Control::ZoomAbs as _, self.it_unit)?;
-        let res = self.get::<2>(Request::GetRes, Control::ZoomAbs as _, self.it_unit)?;
-        let def = self.get::<2>(Request::GetDef, Control::ZoomAbs as _, self.it_unit)?;
-        Ok(Caps {
-            min: u16::from_ne_bytes(min) as _,
-            max: u16::from_ne_bytes(max) as _,
-            res: u16::from_ne_bytes(res) as _,
-            def: u16::from_ne_bytes(def) as _,
-        })
+        Ok(self.caps(ControlId::ZoomAbs)?[0])
     }
 
     pub fn zoom_abs(&self) -> Result<i32, Error> {
-        let cur = self.get::<2>(Request::GetCur, Control::ZoomAbs as _, self.it_unit)?;
-        Ok(u16::from_ne_bytes(cur) as _)
+        Ok(self.get(ControlId::ZoomAbs)?.0[0])
     }
 
     pub fn zoom_abs_set(&self, value: i32) -> Result<(), Error> {
-        let data: u16 = value as _;
-        self.set(Control::ZoomAbs as _, self.it_unit, &data.to_ne_bytes())
+        self.set(ControlId::ZoomAbs, value.into())
     }
 
     pub fn zoom_rel_caps(&self) -> Result<Caps, Error> {
-        let min = self.get::<3>(Request::GetMin, Control::ZoomRel as _, self.it_unit)?;
-        let max = self.get::<3>(Request::GetMax, Control::ZoomRel as _, self.it_unit)?;
-        let res = self.get::<3>(Request::GetRes, Control::ZoomRel as _, self.it_unit)?;
-        let def = self.get::<3>(Request::GetDef, Control::ZoomRel as _, self.it_unit)?;
-        Ok(Caps {
-            min: min[0] as _,
-            max: max[0] as _,
-            res: res[0] as _,
-            def: def[0] as _,
-        })
+        Ok(self.caps(ControlId::ZoomRel)?[0])
     }
 
     pub fn zoom_rel(&self) -> Result<i32, Error> {
-        let cur = self.get::<3>(Request::GetCur, Control::ZoomRel as _, self.it_unit)?;
-        Ok(cur[0] as _)
+        Ok(self.get(ControlId::ZoomRel)?.0[0])
     }
 
+    /// Moves in the direction of `value` at the slowest speed.
     pub fn zoom_rel_set(&self, value: i32) -> Result<(), Error> {
-        let caps = self.zoom_rel_caps()?;
-        let data = [value as u8, 0, caps.res as u8];
-        self.set(Control::ZoomRel as _, self.it_unit, &data)
+        let speed_caps = self.zoom_rel_speed_caps()?;
+        self.zoom_rel_move(value, speed_caps.min)
+    }
+
+    pub fn zoom_rel_speed_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::ZoomRel)?[2])
+    }
+
+    /// Zooms in for a positive `direction`, out for a negative one, and stops for zero.
+    pub fn zoom_rel_move(&self, direction: i32, speed: i32) -> Result<(), Error> {
+        self.set(ControlId::ZoomRel, [direction.signum(), 0, speed].into())
     }
 
     pub fn pan_abs_caps(&self) -> Result<Caps, Error> {
-        let min = self.get::<8>(Request::GetMin, Control::PanTiltAbs as _, self.it_unit)?;
-        let max = self.get::<8>(Request::GetMax, Control::PanTiltAbs as _, self.it_unit)?;
-        let res = self.get::<8>(Request::GetRes, Control::PanTiltAbs as _, self.it_unit)?;
-        let def = self.get::<8>(Request::GetDef, Control::PanTiltAbs as _, self.it_unit)?;
-        Ok(Caps {
-            min: i32::from_ne_bytes(min[..4].try_into().unwrap()),
-            max: i32::from_ne_bytes(max[..4].try_into().unwrap()),
-            res: i32::from_ne_bytes(res[..4].try_into().unwrap()),
-            def: i32::from_ne_bytes(def[..4].try_into().unwrap()),
-        })
+        Ok(self.caps(ControlId::PanTiltAbs)?[0])
     }
 
     pub fn pan_abs(&self) -> Result<i32, Error> {
-        let cur = self.get::<8>(Request::GetCur, Control::PanTiltAbs as _, self.it_unit)?;
-        Ok(i32::from_ne_bytes(cur[..4].try_into().unwrap()))
+        Ok(self.get(ControlId::PanTiltAbs)?.0[0])
     }
 
     pub fn pan_abs_set(&self, value: i32) -> Result<(), Error> {
         let tilt_cur = self.tilt_abs()?;
-        let data = [value, tilt_cur];
-        let data: [u8; 8] = unsafe { mem::transmute(data) };
-        self.set(Control::PanTiltAbs as _, self.it_unit, &data)
+        self.set(ControlId::PanTiltAbs, [value, tilt_cur].into())
     }
 
     pub fn pan_rel_caps(&self) -> Result<Caps, Error> {
-        let min = self.get::<4>(Request::GetMin, Control::PanTiltRel as _, self.it_unit)?;
-        let max = self.get::<4>(Request::GetMax, Control::PanTiltRel as _, self.it_unit)?;
-        let res = self.get::<4>(Request::GetRes, Control::PanTiltRel as _, self.it_unit)?;
-        let def = self.get::<4>(Request::GetDef, Control::PanTiltRel as _, self.it_unit)?;
-        Ok(Caps {
-            min: min[0] as _,
-            max: max[0] as _,
-            res: res[0] as _,
-            def: def[0] as _,
-        })
+        Ok(self.caps(ControlId::PanTiltRel)?[0])
     }
 
     pub fn pan_rel(&self) -> Result<i32, Error> {
-        let cur = self.get::<4>(Request::GetCur, Control::PanTiltRel as _, self.it_unit)?;
-        Ok(cur[0] as _)
+        Ok(self.get(ControlId::PanTiltRel)?.0[0])
     }
 
+    /// Moves in the direction of `value` at the slowest speed.
     pub fn pan_rel_set(&self, value: i32) -> Result<(), Error> {
-        let pan_caps = self.pan_rel_caps()?;
-        let tilt_caps = self.tilt_rel_caps()?;
-        let tilt_cur = self.tilt_rel()?;
-        let data = [
-            value as u8,
-            pan_caps.res as u8,
-            tilt_cur as u8,
-            tilt_caps.res as u8,
-        ];
-        self.set(Control::PanTiltRel as _, self.it_unit, &data)
+        let speed_caps = self.pan_rel_speed_caps()?;
+        self.pan_rel_move(value, speed_caps.min)
+    }
+
+    pub fn pan_rel_speed_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::PanTiltRel)?[1])
+    }
+
+    /// Pans clockwise for a positive `direction`, counter-clockwise for a negative one, and
+    /// stops for zero. The tilt movement is left as is.
+    pub fn pan_rel_move(&self, direction: i32, speed: i32) -> Result<(), Error> {
+        let cur = self.get(ControlId::PanTiltRel)?.0;
+        self.set(
+            ControlId::PanTiltRel,
+            [direction.signum(), speed, cur[2], cur[3]].into(),
+        )
     }
 
     pub fn tilt_abs_caps(&self) -> Result<Caps, Error> {
-        let min = self.get::<8>(Request::GetMin, Control::PanTiltAbs as _, self.it_unit)?;
-        let max = self.get::<8>(Request::GetMax, Control::PanTiltAbs as _, self.it_unit)?;
-        let res = self.get::<8>(Request::GetRes, Control::PanTiltAbs as _, self.it_unit)?;
-        let def = self.get::<8>(Request::GetDef, Control::PanTiltAbs as _, self.it_unit)?;
-        Ok(Caps {
-            min: i32::from_ne_bytes(min[4..].try_into().unwrap()),
-            max: i32::from_ne_bytes(max[4..].try_into().unwrap()),
-            res: i32::from_ne_bytes(res[4..].try_into().unwrap()),
-            def: i32::from_ne_bytes(def[4..].try_into().unwrap()),
-        })
+        Ok(self.caps(ControlId::PanTiltAbs)?[1])
     }
 
     pub fn tilt_abs(&self) -> Result<i32, Error> {
-        let cur = self.get::<8>(Request::GetCur, Control::PanTiltAbs as _, self.it_unit)?;
-        Ok(i32::from_ne_bytes(cur[4..].try_into().unwrap()))
+        Ok(self.get(ControlId::PanTiltAbs)?.0[1])
     }
 
     pub fn tilt_abs_set(&self, value: i32) -> Result<(), Error> {
         let pan_cur = self.pan_abs()?;
-        let data = [pan_cur, value];
-        let data: [u8; 8] = unsafe { mem::transmute(data) };
-        self.set(Control::PanTiltAbs as _, self.it_unit, &data)
+        self.set(ControlId::PanTiltAbs, [pan_cur, value].into())
     }
 
     pub fn tilt_rel_caps(&self) -> Result<Caps, Error> {
-        let min = self.get::<4>(Request::GetMin, Control::PanTiltRel as _, self.it_unit)?;
-        let max = self.get::<4>(Request::GetMax, Control::PanTiltRel as _, self.it_unit)?;
-        let res = self.get::<4>(Request::GetRes, Control::PanTiltRel as _, self.it_unit)?;
-        let def = self.get::<4>(Request::GetDef, Control::PanTiltRel as _, self.it_unit)?;
-        Ok(Caps {
-            min: min[2] as _,
-            max: max[2] as _,
-            res: res[2] as _,
-            def: def[2] as _,
-        })
+        Ok(self.caps(ControlId::PanTiltRel)?[2])
     }
 
     pub fn tilt_rel(&self) -> Result<i32, Error> {
-        let cur = self.get::<4>(Request::GetCur, Control::PanTiltRel as _, self.it_unit)?;
-        Ok(cur[2] as _)
+        Ok(self.get(ControlId::PanTiltRel)?.0[2])
     }
 
+    /// Moves in the direction of `value` at the slowest speed.
     pub fn tilt_rel_set(&self, value: i32) -> Result<(), Error> {
-        let pan_caps = self.pan_rel_caps()?;
-        let pan_cur = self.pan_rel()?;
-        let tilt_caps = self.tilt_rel_caps()?;
-        let data = [
-            pan_cur as u8,
-            pan_caps.res as u8,
-            value as u8,
-            tilt_caps.res as u8,
-        ];
-        self.set(Control::PanTiltRel as _, self.it_unit, &data)
+        let speed_caps = self.tilt_rel_speed_caps()?;
+        self.tilt_rel_move(value, speed_caps.min)
+    }
+
+    pub fn tilt_rel_speed_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::PanTiltRel)?[3])
+    }
+
+    /// Tilts up for a positive `direction`, down for a negative one, and stops for zero. The
+    /// pan movement is left as is.
+    pub fn tilt_rel_move(&self, direction: i32, speed: i32) -> Result<(), Error> {
+        let cur = self.get(ControlId::PanTiltRel)?.0;
+        self.set(
+            ControlId::PanTiltRel,
+            [cur[0], cur[1], direction.signum(), speed].into(),
+        )
+    }
+
+    pub fn pan_tilt_abs(&self) -> Result<(i32, i32), Error> {
+        let cur = self.get(ControlId::PanTiltAbs)?.0;
+        Ok((cur[0], cur[1]))
+    }
+
+    pub fn pan_tilt_abs_set(&self, pan: i32, tilt: i32) -> Result<(), Error> {
+        self.set(ControlId::PanTiltAbs, [pan, tilt].into())
+    }
+
+    pub fn pan_tilt_rel_set(
+        &self,
+        pan_direction: i32,
+        pan_speed: i32,
+        tilt_direction: i32,
+        tilt_speed: i32,
+    ) -> Result<(), Error> {
+        self.set(
+            ControlId::PanTiltRel,
+            [
+                pan_direction.signum(),
+                pan_speed,
+                tilt_direction.signum(),
+                tilt_speed,
+            ]
+            .into(),
+        )
+    }
+
+    pub fn brightness_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Brightness)?[0])
+    }
+
+    pub fn brightness(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Brightness)?.0[0])
+    }
+
+    pub fn brightness_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Brightness, value.into())
+    }
+
+    pub fn contrast_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Contrast)?[0])
+    }
+
+    pub fn contrast(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Contrast)?.0[0])
+    }
+
+    pub fn contrast_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Contrast, value.into())
+    }
+
+    pub fn hue_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Hue)?[0])
+    }
+
+    pub fn hue(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Hue)?.0[0])
+    }
+
+    pub fn hue_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Hue, value.into())
+    }
+
+    pub fn saturation_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Saturation)?[0])
+    }
+
+    pub fn saturation(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Saturation)?.0[0])
+    }
+
+    pub fn saturation_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Saturation, value.into())
+    }
+
+    pub fn sharpness_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Sharpness)?[0])
+    }
+
+    pub fn sharpness(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Sharpness)?.0[0])
+    }
+
+    pub fn sharpness_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Sharpness, value.into())
+    }
+
+    pub fn gamma_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Gamma)?[0])
+    }
+
+    pub fn gamma(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Gamma)?.0[0])
+    }
+
+    pub fn gamma_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Gamma, value.into())
+    }
+
+    pub fn gain_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::Gain)?[0])
+    }
+
+    pub fn gain(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::Gain)?.0[0])
+    }
+
+    pub fn gain_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::Gain, value.into())
+    }
+
+    pub fn hue_auto(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::HueAuto)?.0[0] != 0)
+    }
+
+    pub fn hue_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.set(ControlId::HueAuto, (value as i32).into())
+    }
+
+    pub fn contrast_auto(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::ContrastAuto)?.0[0] != 0)
+    }
+
+    pub fn contrast_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.set(ControlId::ContrastAuto, (value as i32).into())
+    }
+
+    pub fn white_balance_temperature_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::WhiteBalanceTemperature)?[0])
+    }
+
+    pub fn white_balance_temperature(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::WhiteBalanceTemperature)?.0[0])
+    }
+
+    pub fn white_balance_temperature_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::WhiteBalanceTemperature, value.into())
+    }
+
+    pub fn white_balance_temperature_auto(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::WhiteBalanceTemperatureAuto)?.0[0] != 0)
+    }
+
+    pub fn white_balance_temperature_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.set(
+            ControlId::WhiteBalanceTemperatureAuto,
+            (value as i32).into(),
+        )
+    }
+
+    pub fn white_balance_blue_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::WhiteBalanceComponent)?[0])
+    }
+
+    pub fn white_balance_blue(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::WhiteBalanceComponent)?.0[0])
+    }
+
+    pub fn white_balance_blue_set(&self, value: i32) -> Result<(), Error> {
+        let red_cur = self.white_balance_red()?;
+        self.white_balance_component_set(value, red_cur)
+    }
+
+    pub fn white_balance_red_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::WhiteBalanceComponent)?[1])
+    }
+
+    pub fn white_balance_red(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::WhiteBalanceComponent)?.0[1])
+    }
+
+    pub fn white_balance_red_set(&self, value: i32) -> Result<(), Error> {
+        let blue_cur = self.white_balance_blue()?;
+        self.white_balance_component_set(blue_cur, value)
+    }
+
+    pub fn white_balance_component_set(&self, blue: i32, red: i32) -> Result<(), Error> {
+        self.set(ControlId::WhiteBalanceComponent, [blue, red].into())
+    }
+
+    pub fn white_balance_component_auto(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::WhiteBalanceComponentAuto)?.0[0] != 0)
+    }
+
+    pub fn white_balance_component_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.set(ControlId::WhiteBalanceComponentAuto, (value as i32).into())
+    }
+
+    pub fn ae_modes(&self) -> Result<Vec<AeMode>, Error> {
+        let res = self.get_control(Request::GetRes, ControlId::AeMode)?;
+        Ok(AeMode::from_mask(res[0]))
+    }
+
+    pub fn ae_mode(&self) -> Result<AeMode, Error> {
+        let cur = self.get(ControlId::AeMode)?.0[0];
+        AeMode::from_bits(cur as _).ok_or(Error::InvalidValue)
+    }
+
+    pub fn ae_mode_set(&self, mode: AeMode) -> Result<(), Error> {
+        if !self.ae_modes()?.contains(&mode) {
+            return Err(Error::InvalidValue);
+        }
+        self.set(ControlId::AeMode, (mode as i32).into())
+    }
+
+    pub fn ae_priority(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::AePriority)?.0[0] != 0)
+    }
+
+    pub fn ae_priority_set(&self, value: bool) -> Result<(), Error> {
+        self.set(ControlId::AePriority, (value as i32).into())
+    }
+
+    /// In units of 100µs.
+    pub fn exposure_time_abs_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::ExposureTimeAbs)?[0])
+    }
+
+    pub fn exposure_time_abs(&self) -> Result<Duration, Error> {
+        let cur = self.get(ControlId::ExposureTimeAbs)?.0[0] as u32;
+        Ok(Duration::from_micros(cur as u64 * 100))
+    }
+
+    pub fn exposure_time_abs_set(&self, value: Duration) -> Result<(), Error> {
+        let units = (value.as_micros() / 100).min(u32::MAX as _) as u32;
+        self.set(ControlId::ExposureTimeAbs, (units as i32).into())
+    }
+
+    pub fn exposure_time_rel(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::ExposureTimeRel)?.0[0])
+    }
+
+    /// Steps the exposure time by one: positive longer, negative shorter, `0` default.
+    pub fn exposure_time_rel_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::ExposureTimeRel, value.signum().into())
+    }
+
+    /// In millimetres.
+    pub fn focus_abs_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::FocusAbs)?[0])
+    }
+
+    pub fn focus_abs(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::FocusAbs)?.0[0])
+    }
+
+    pub fn focus_abs_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::FocusAbs, value.into())
+    }
+
+    /// The range of the speed of relative focus moves.
+    pub fn focus_rel_speed_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::FocusRel)?[1])
+    }
+
+    pub fn focus_rel(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::FocusRel)?.0[0])
+    }
+
+    /// Moves focus towards infinity for a positive `direction`, towards near for a negative
+    /// one, and stops for zero.
+    pub fn focus_rel_set(&self, direction: i32, speed: i32) -> Result<(), Error> {
+        self.set(ControlId::FocusRel, [direction.signum(), speed].into())
+    }
+
+    pub fn focus_simple_range(&self) -> Result<FocusSimpleRange, Error> {
+        let cur = self.get(ControlId::FocusSimple)?.0[0];
+        FocusSimpleRange::from_u8(cur as _).ok_or(Error::InvalidValue)
+    }
+
+    pub fn focus_simple_range_set(&self, value: FocusSimpleRange) -> Result<(), Error> {
+        self.set(ControlId::FocusSimple, (value as i32).into())
+    }
+
+    pub fn focus_auto(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::FocusAuto)?.0[0] != 0)
+    }
+
+    pub fn focus_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.set(ControlId::FocusAuto, (value as i32).into())
+    }
+
+    /// In units of f-stop * 100.
+    pub fn iris_abs_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::IrisAbs)?[0])
+    }
+
+    pub fn iris_abs(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::IrisAbs)?.0[0])
+    }
+
+    pub fn iris_abs_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::IrisAbs, value.into())
+    }
+
+    pub fn iris_rel(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::IrisRel)?.0[0])
+    }
+
+    /// Steps the aperture by one: `1` open, `-1` close, `0` default.
+    pub fn iris_rel_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::IrisRel, value.signum().into())
+    }
+
+    /// In degrees.
+    pub fn roll_abs_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::RollAbs)?[0])
+    }
+
+    pub fn roll_abs(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::RollAbs)?.0[0])
+    }
+
+    pub fn roll_abs_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::RollAbs, value.into())
+    }
+
+    /// The range of the speed of relative roll moves.
+    pub fn roll_rel_speed_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::RollRel)?[1])
+    }
+
+    pub fn roll_rel(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::RollRel)?.0[0])
+    }
+
+    /// Rolls clockwise for a positive `direction`, counter-clockwise for a negative one, and
+    /// stops for zero.
+    pub fn roll_rel_set(&self, direction: i32, speed: i32) -> Result<(), Error> {
+        self.set(ControlId::RollRel, [direction.signum(), speed].into())
+    }
+
+    pub fn scanning_mode(&self) -> Result<ScanningMode, Error> {
+        let cur = self.get(ControlId::ScanningMode)?.0[0];
+        ScanningMode::from_u8(cur as _).ok_or(Error::InvalidValue)
+    }
+
+    pub fn scanning_mode_set(&self, value: ScanningMode) -> Result<(), Error> {
+        self.set(ControlId::ScanningMode, (value as i32).into())
+    }
+
+    pub fn privacy(&self) -> Result<bool, Error> {
+        Ok(self.get(ControlId::Privacy)?.0[0] != 0)
+    }
+
+    pub fn privacy_set(&self, value: bool) -> Result<(), Error> {
+        self.set(ControlId::Privacy, (value as i32).into())
+    }
+
+    pub fn power_line_frequency(&self) -> Result<PowerLineFrequency, Error> {
+        let cur = self.get(ControlId::PowerLineFrequency)?.0[0];
+        PowerLineFrequency::from_u8(cur as _).ok_or(Error::InvalidValue)
+    }
+
+    pub fn power_line_frequency_set(&self, value: PowerLineFrequency) -> Result<(), Error> {
+        self.check_range(ControlId::PowerLineFrequency, value as _)?;
+        self.set(ControlId::PowerLineFrequency, (value as i32).into())
+    }
+
+    pub fn backlight_compensation_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::BacklightCompensation)?[0])
+    }
+
+    pub fn backlight_compensation(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::BacklightCompensation)?.0[0])
+    }
+
+    pub fn backlight_compensation_set(&self, value: i32) -> Result<(), Error> {
+        self.check_range(ControlId::BacklightCompensation, value)?;
+        self.set(ControlId::BacklightCompensation, value.into())
+    }
+
+    pub fn digital_multiplier_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::DigitalMultiplier)?[0])
+    }
+
+    pub fn digital_multiplier(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::DigitalMultiplier)?.0[0])
+    }
+
+    pub fn digital_multiplier_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::DigitalMultiplier, value.into())
+    }
+
+    pub fn digital_multiplier_limit_caps(&self) -> Result<Caps, Error> {
+        Ok(self.caps(ControlId::DigitalMultiplierLimit)?[0])
+    }
+
+    pub fn digital_multiplier_limit(&self) -> Result<i32, Error> {
+        Ok(self.get(ControlId::DigitalMultiplierLimit)?.0[0])
+    }
+
+    pub fn digital_multiplier_limit_set(&self, value: i32) -> Result<(), Error> {
+        self.set(ControlId::DigitalMultiplierLimit, value.into())
+    }
+
+    /// Optical zoom followed by the digital multiplier up to its current limit, as a single
+    /// range.
+    pub fn zoom_effective_caps(&self) -> Result<Caps, Error> {
+        Ok(self.effective_zoom()?.caps())
+    }
+
+    pub fn zoom_effective(&self) -> Result<i32, Error> {
+        let zoom = self.effective_zoom()?;
+        Ok(zoom.value(self.zoom_abs()?, self.digital_multiplier()?))
+    }
+
+    pub fn zoom_effective_set(&self, value: i32) -> Result<(), Error> {
+        self.effective_zoom()?.set(
+            value,
+            |v| self.zoom_abs_set(v),
+            |v| self.digital_multiplier_set(v),
+        )
+    }
+
+    fn effective_zoom(&self) -> Result<EffectiveZoom, Error> {
+        Ok(EffectiveZoom {
+            optical: self.zoom_abs_caps()?,
+            digital: self.digital_multiplier_caps()?,
+            limit: self.digital_multiplier_limit().ok(),
+        })
+    }
+}
+
+impl<T: Transport> Device<T> {
+    /// Claims the VideoControl interface and listens on its status interrupt endpoint.
+    pub fn events(&self) -> Result<Events, Error> {
+        let Some((ep, packet_size)) = self.status_ep else {
+            return Err(Error::EndpointNotFound);
+        };
+        let inf = self.inner.claim_interface(self.inf_no)?;
+        Ok(Events::new(inf, ep, packet_size))
+    }
+
+    /// Listens on the status interrupt endpoint for changes of the privacy shutter.
+    pub fn privacy_changes(&self) -> Result<PrivacyChanges, Error> {
+        Ok(PrivacyChanges {
+            events: self.events()?,
+            unit: self.it_unit,
+        })
     }
 }
 
+/// Yields the new privacy state each time the camera reports it changed.
+pub struct PrivacyChanges {
+    events: Events,
+    unit: u8,
+}
+
+impl Iterator for PrivacyChanges {
+    type Item = Result<bool, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.events.next()? {
+                Ok(Event::Control {
+                    originator,
+                    selector,
+                    attribute: Attribute::Value,
+                    value,
+                }) if originator == self.unit && selector == ControlId::Privacy.selector() => {
+                    return Some(Ok(value.first().is_some_and(|&v| v != 0)));
+                }
+                Ok(_) => {}
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum Request {
     SetCur = 0x01,
@@ -288,23 +1152,10 @@ pub enum Request {
     GetDef = 0x87,
 }
 
-#[repr(u8)]
-enum Control {
-    ZoomAbs = 0x0b,
-    ZoomRel = 0x0c,
-    PanTiltAbs = 0x0d,
-    PanTiltRel = 0x0e,
-}
+const VC_REQUEST_ERROR_CODE_CONTROL: u8 = 0x02;
 
 #[repr(u8)]
 enum UsbClass {
     Video = 0x0e,
     VideoControl = 0x01,
 }
-
-#[repr(u8)]
-enum DescriptorType {
-    CSInterface = 0x24,
-    VCInputTerminal = 0x02,
-    VCProcessingUnit = 0x05,
-}
@@ -0,0 +1,201 @@
+use super::{Device, Request, TransferPolicy, VC_REQUEST_ERROR_CODE_CONTROL};
+use crate::{
+    control::{ControlId, ControlInfo, RequestErrorCode, UnitKind, Value},
+    descriptors::VideoControl,
+    Caps, Error,
+};
+use async_io::Timer;
+use futures_lite::future;
+use nusb::transfer::{self, Completion, TransferError};
+use std::future::Future;
+
+/// A `Device` driven by nusb's future-based control transfers instead of blocking ones.
+///
+/// The futures do not depend on any particular executor. Transfers are timed out and retried
+/// according to the `TransferPolicy` of the `Device` it was made from. Clones share the same USB
+/// handle.
+#[derive(Clone)]
+pub struct AsyncDevice {
+    inf_no: u8,
+    it_unit: u8,
+    pu_unit: u8,
+    policy: TransferPolicy,
+    descriptors: VideoControl,
+    inner: nusb::Device,
+}
+
+impl From<Device> for AsyncDevice {
+    fn from(device: Device) -> Self {
+        AsyncDevice {
+            inf_no: device.inf_no,
+            it_unit: device.it_unit,
+            pu_unit: device.pu_unit,
+            policy: device.policy,
+            descriptors: device.descriptors,
+            inner: device.inner,
+        }
+    }
+}
+
+impl AsyncDevice {
+    pub fn descriptors(&self) -> &VideoControl {
+        &self.descriptors
+    }
+
+    pub fn policy(&self) -> &TransferPolicy {
+        &self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: TransferPolicy) {
+        self.policy = policy;
+    }
+
+    pub async fn get_raw(
+        &self,
+        req: Request,
+        control_code: u8,
+        unit: u8,
+        len: u16,
+    ) -> Result<Vec<u8>, Error> {
+        let r = self
+            .retry(|| {
+                self.inner.control_in(transfer::ControlIn {
+                    control_type: transfer::ControlType::Class,
+                    recipient: transfer::Recipient::Interface,
+                    request: req as _,
+                    value: (control_code as u16) << 8,
+                    index: (unit as u16) << 8 | self.inf_no as u16,
+                    length: len,
+                })
+            })
+            .await;
+        match r {
+            Ok(data) => Ok(data),
+            Err(err) => Err(self.transfer_error(err).await),
+        }
+    }
+
+    pub async fn set_raw(&self, control_code: u8, unit: u8, data: &[u8]) -> Result<(), Error> {
+        let r = self
+            .retry(|| {
+                self.inner.control_out(transfer::ControlOut {
+                    control_type: transfer::ControlType::Class,
+                    recipient: transfer::Recipient::Interface,
+                    request: Request::SetCur as _,
+                    value: (control_code as u16) << 8,
+                    index: (unit as u16) << 8 | self.inf_no as u16,
+                    data,
+                })
+            })
+            .await;
+        match r {
+            Ok(_) => Ok(()),
+            Err(err) => Err(self.transfer_error(err).await),
+        }
+    }
+
+    async fn retry<T, F>(&self, mut f: impl FnMut() -> F) -> Result<T, TransferError>
+    where
+        F: Future<Output = Completion<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.timeout(f()).await {
+                Err(err)
+                    if attempt < self.policy.retries && self.policy.retryable.contains(&err) =>
+                {
+                    attempt += 1;
+                    Timer::after(self.policy.backoff * attempt).await;
+                }
+                r => return r,
+            }
+        }
+    }
+
+    /// Fails with `Cancelled`, as a timed out blocking transfer does, if `transfer` takes longer
+    /// than the policy allows. Dropping the nusb future cancels the transfer.
+    async fn timeout<T>(
+        &self,
+        transfer: impl Future<Output = Completion<T>>,
+    ) -> Result<T, TransferError> {
+        future::or(async { transfer.await.into_result() }, async {
+            Timer::after(self.policy.timeout).await;
+            Err(TransferError::Cancelled)
+        })
+        .await
+    }
+
+    async fn transfer_error(&self, err: TransferError) -> Error {
+        if err != TransferError::Stall {
+            return err.into();
+        }
+
+        let r = self
+            .timeout(self.inner.control_in(transfer::ControlIn {
+                control_type: transfer::ControlType::Class,
+                recipient: transfer::Recipient::Interface,
+                request: Request::GetCur as _,
+                value: (VC_REQUEST_ERROR_CODE_CONTROL as u16) << 8,
+                index: self.inf_no as u16,
+                length: 1,
+            }))
+            .await;
+        let code = r.ok().and_then(|data| data.first().copied());
+        match code.and_then(RequestErrorCode::from_u8) {
+            Some(code) => Error::Uvc(code),
+            None => err.into(),
+        }
+    }
+
+    fn unit(&self, kind: UnitKind) -> u8 {
+        match kind {
+            UnitKind::CameraTerminal => self.it_unit,
+            UnitKind::ProcessingUnit => self.pu_unit,
+        }
+    }
+
+    async fn get_control(&self, req: Request, id: ControlId) -> Result<Vec<u8>, Error> {
+        let len = id.layout().len as u16;
+        self.get_raw(req, id.selector(), self.unit(id.unit()), len)
+            .await
+    }
+
+    pub async fn info(&self, id: ControlId) -> Result<ControlInfo, Error> {
+        let data = self
+            .get_raw(Request::GetInfo, id.selector(), self.unit(id.unit()), 1)
+            .await?;
+        Ok(ControlInfo::from_bits(
+            data.first().copied().unwrap_or_default(),
+        ))
+    }
+
+    /// Returns the range of every field of the control.
+    pub async fn caps(&self, id: ControlId) -> Result<Vec<Caps>, Error> {
+        let layout = id.layout();
+        let min = self.get_control(Request::GetMin, id).await?;
+        let max = self.get_control(Request::GetMax, id).await?;
+        let res = self.get_control(Request::GetRes, id).await?;
+        let def = self.get_control(Request::GetDef, id).await?;
+        Ok(layout
+            .fields
+            .iter()
+            .map(|f| Caps {
+                min: f.read(&min) as _,
+                max: f.read(&max) as _,
+                res: f.read(&res) as _,
+                def: f.read(&def) as _,
+            })
+            .collect())
+    }
+
+    pub async fn get(&self, id: ControlId) -> Result<Value, Error> {
+        let cur = self.get_control(Request::GetCur, id).await?;
+        Ok(Value(id.layout().decode(&cur)))
+    }
+
+    pub async fn set(&self, id: ControlId, value: Value) -> Result<(), Error> {
+        let data = id.layout().encode(&value.0);
+        self.set_raw(id.selector(), self.unit(id.unit()), &data)
+            .await
+    }
+}
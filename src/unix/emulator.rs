@@ -0,0 +1,578 @@
+use super::{Device, Request, Transport};
+use crate::{
+    codec::{Field, Layout},
+    descriptors::VideoControl,
+};
+use nusb::transfer::TransferError;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// A software camera answering class-specific VideoControl requests the way a UVC 1.5 device
+/// does, including stalls and `VC_REQUEST_ERROR_CODE_CONTROL`.
+///
+/// Clones share the same device state. `emulator.device(inf_no).boxed()` converts into an
+/// `ffi::Device`, so the C API can be driven against it as well.
+#[derive(Clone)]
+pub struct Emulator {
+    state: Arc<Mutex<State>>,
+}
+
+struct State {
+    descriptors: VideoControl,
+    controls: HashMap<(u8, u8), EmulatedControl>,
+    auto_links: Vec<AutoLink>,
+    error_code: u8,
+    quirks: Quirks,
+}
+
+struct AutoLink {
+    auto: (u8, u8),
+    target: (u8, u8),
+    is_auto: fn(&[u8]) -> bool,
+}
+
+/// Deviations from the spec seen on real devices.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Quirks {
+    /// Silently clamp out-of-range SET_CUR values instead of stalling.
+    pub clamp_out_of_range: bool,
+    /// Accept values that are not a multiple of GET_RES from GET_MIN.
+    pub ignore_res: bool,
+    /// Stall on `VC_REQUEST_ERROR_CODE_CONTROL` as if it were not implemented.
+    pub no_error_code_control: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmulatedControl {
+    pub info: u8,
+    pub fields: &'static [Field],
+    pub min: Option<Vec<u8>>,
+    pub max: Option<Vec<u8>>,
+    pub res: Option<Vec<u8>>,
+    pub def: Option<Vec<u8>>,
+    pub cur: Vec<u8>,
+}
+
+impl EmulatedControl {
+    /// A GET/SET control without any range, holding zeros.
+    pub fn new(layout: Layout) -> Self {
+        EmulatedControl {
+            info: INFO_GET | INFO_SET,
+            fields: layout.fields,
+            min: None,
+            max: None,
+            res: None,
+            def: None,
+            cur: vec![0; layout.len],
+        }
+    }
+
+    /// Sets GET_MIN/GET_MAX/GET_RES/GET_DEF, one value per field, and resets the current
+    /// value to the default.
+    pub fn range(mut self, min: &[i32], max: &[i32], res: &[i32], def: &[i32]) -> Self {
+        let layout = Layout {
+            len: self.cur.len(),
+            fields: self.fields,
+        };
+        self.min = Some(layout.encode(min));
+        self.max = Some(layout.encode(max));
+        self.res = Some(layout.encode(res));
+        self.def = Some(layout.encode(def));
+        self.cur = layout.encode(def);
+        self
+    }
+
+    pub fn info(mut self, info: u8) -> Self {
+        self.info = info;
+        self
+    }
+}
+
+impl Emulator {
+    pub fn new(descriptors: VideoControl) -> Self {
+        Emulator {
+            state: Arc::new(Mutex::new(State {
+                descriptors,
+                controls: HashMap::new(),
+                auto_links: vec![],
+                error_code: ERROR_NO_ERROR,
+                quirks: Quirks::default(),
+            })),
+        }
+    }
+
+    pub fn descriptors(&self) -> VideoControl {
+        self.state.lock().unwrap().descriptors.clone()
+    }
+
+    pub fn device(&self, inf_no: u8) -> Device<Emulator> {
+        Device::with_transport(self.clone(), inf_no, self.descriptors())
+    }
+
+    pub fn control(&self, unit: u8, selector: u8, control: EmulatedControl) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .controls
+            .insert((unit, selector), control);
+        self
+    }
+
+    pub fn quirks(&self, quirks: Quirks) -> &Self {
+        self.state.lock().unwrap().quirks = quirks;
+        self
+    }
+
+    /// Marks `target` as disabled due to automatic mode (GET_INFO D2) whenever `is_auto`
+    /// holds for the current value of `auto`, rejecting SET_CUR on it with a wrong-state error.
+    pub fn link_auto(&self, auto: (u8, u8), target: (u8, u8), is_auto: fn(&[u8]) -> bool) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        state.auto_links.push(AutoLink {
+            auto,
+            target,
+            is_auto,
+        });
+        state.update_auto();
+        self
+    }
+
+    /// The current value of a control, as the device holds it.
+    pub fn cur(&self, unit: u8, selector: u8) -> Option<Vec<u8>> {
+        let state = self.state.lock().unwrap();
+        state.controls.get(&(unit, selector)).map(|c| c.cur.clone())
+    }
+
+    /// Changes a control behind the host's back, as autoupdate controls do.
+    pub fn set_cur(&self, unit: u8, selector: u8, data: &[u8]) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(c) = state.controls.get_mut(&(unit, selector)) {
+            c.cur = data.to_vec();
+        }
+        state.update_auto();
+    }
+
+    pub fn error_code(&self) -> u8 {
+        self.state.lock().unwrap().error_code
+    }
+}
+
+impl State {
+    fn has_unit(&self, unit: u8) -> bool {
+        let d = &self.descriptors;
+        d.input_terminals.iter().any(|t| t.id == unit)
+            || d.output_terminals.iter().any(|t| t.id == unit)
+            || d.selector_units.iter().any(|u| u.id == unit)
+            || d.processing_units.iter().any(|u| u.id == unit)
+            || d.encoding_units.iter().any(|u| u.id == unit)
+            || d.extension_units.iter().any(|u| u.id == unit)
+    }
+
+    fn update_auto(&mut self) {
+        for link in &self.auto_links {
+            let Some(auto) = self.controls.get(&link.auto) else {
+                continue;
+            };
+            let disabled = (link.is_auto)(&auto.cur);
+            if let Some(target) = self.controls.get_mut(&link.target) {
+                if disabled {
+                    target.info |= INFO_DISABLED_AUTO;
+                } else {
+                    target.info &= !INFO_DISABLED_AUTO;
+                }
+            }
+        }
+    }
+
+    fn control(&self, unit: u8, selector: u8) -> Result<&EmulatedControl, u8> {
+        if !self.has_unit(unit) {
+            return Err(ERROR_INVALID_UNIT);
+        }
+        self.controls
+            .get(&(unit, selector))
+            .ok_or(ERROR_INVALID_CONTROL)
+    }
+
+    fn get(&self, request: u8, unit: u8, selector: u8) -> Result<Vec<u8>, u8> {
+        if unit == 0 {
+            return match selector {
+                VC_REQUEST_ERROR_CODE_CONTROL if !self.quirks.no_error_code_control => {
+                    match request {
+                        r if r == Request::GetCur as u8 => Ok(vec![self.error_code]),
+                        r if r == Request::GetInfo as u8 => Ok(vec![INFO_GET]),
+                        _ => Err(ERROR_INVALID_REQUEST),
+                    }
+                }
+                _ => Err(ERROR_INVALID_CONTROL),
+            };
+        }
+
+        let c = self.control(unit, selector)?;
+        let v = match request {
+            r if r == Request::GetInfo as u8 => return Ok(vec![c.info]),
+            r if r == Request::GetLen as u8 => {
+                return Ok((c.cur.len() as u16).to_le_bytes().into())
+            }
+            r if r == Request::GetCur as u8 => Some(&c.cur),
+            r if r == Request::GetMin as u8 => c.min.as_ref(),
+            r if r == Request::GetMax as u8 => c.max.as_ref(),
+            r if r == Request::GetRes as u8 => c.res.as_ref(),
+            r if r == Request::GetDef as u8 => c.def.as_ref(),
+            _ => None,
+        };
+        if c.info & INFO_GET == 0 {
+            return Err(ERROR_INVALID_REQUEST);
+        }
+        v.cloned().ok_or(ERROR_INVALID_REQUEST)
+    }
+
+    fn set(&mut self, request: u8, unit: u8, selector: u8, data: &[u8]) -> Result<(), u8> {
+        if request != Request::SetCur as u8 {
+            return Err(ERROR_INVALID_REQUEST);
+        }
+        if unit == 0 {
+            return Err(ERROR_INVALID_CONTROL);
+        }
+
+        let quirks = self.quirks;
+        let c = self.control(unit, selector)?;
+        if c.info & INFO_SET == 0 {
+            return Err(ERROR_INVALID_REQUEST);
+        }
+        if c.info & INFO_DISABLED_AUTO != 0 {
+            return Err(ERROR_WRONG_STATE);
+        }
+        if data.len() != c.cur.len() {
+            return Err(ERROR_INVALID_REQUEST);
+        }
+
+        let mut cur = data.to_vec();
+        for f in c.fields {
+            let mut v = f.read(&cur);
+            let min = c.min.as_deref().map(|b| f.read(b));
+            let max = c.max.as_deref().map(|b| f.read(b));
+
+            if min.is_some_and(|min| v < min) || max.is_some_and(|max| v > max) {
+                if !quirks.clamp_out_of_range {
+                    return Err(ERROR_OUT_OF_RANGE);
+                }
+                v = v.clamp(min.unwrap_or(v), max.unwrap_or(v));
+                f.write(&mut cur, v);
+            }
+
+            let res = c.res.as_deref().map(|b| f.read(b)).unwrap_or(0);
+            if !quirks.ignore_res && res > 0 && (v - min.unwrap_or(0)) % res != 0 {
+                return Err(ERROR_INVALID_VALUE_WITHIN_RANGE);
+            }
+        }
+
+        self.controls.get_mut(&(unit, selector)).unwrap().cur = cur;
+        self.update_auto();
+        Ok(())
+    }
+}
+
+impl Transport for Emulator {
+    fn control_in(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &mut [u8],
+        _timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        let mut state = self.state.lock().unwrap();
+        let selector = (value >> 8) as u8;
+        let unit = (index >> 8) as u8;
+
+        match state.get(request, unit, selector) {
+            Ok(r) => {
+                // Reading the error code control must not clobber the code it reports.
+                if !(unit == 0 && selector == VC_REQUEST_ERROR_CODE_CONTROL) {
+                    state.error_code = ERROR_NO_ERROR;
+                }
+                let len = r.len().min(data.len());
+                data[..len].copy_from_slice(&r[..len]);
+                Ok(len)
+            }
+            Err(code) => {
+                state.error_code = code;
+                Err(TransferError::Stall)
+            }
+        }
+    }
+
+    fn control_out(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &[u8],
+        _timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        let mut state = self.state.lock().unwrap();
+        match state.set(request, (index >> 8) as _, (value >> 8) as _, data) {
+            Ok(()) => {
+                state.error_code = ERROR_NO_ERROR;
+                Ok(data.len())
+            }
+            Err(code) => {
+                state.error_code = code;
+                Err(TransferError::Stall)
+            }
+        }
+    }
+}
+
+pub const INFO_GET: u8 = 0x01;
+pub const INFO_SET: u8 = 0x02;
+pub const INFO_DISABLED_AUTO: u8 = 0x04;
+pub const INFO_AUTOUPDATE: u8 = 0x08;
+pub const INFO_ASYNC: u8 = 0x10;
+
+const VC_REQUEST_ERROR_CODE_CONTROL: u8 = 0x02;
+
+const ERROR_NO_ERROR: u8 = 0x00;
+const ERROR_WRONG_STATE: u8 = 0x02;
+const ERROR_OUT_OF_RANGE: u8 = 0x04;
+const ERROR_INVALID_UNIT: u8 = 0x05;
+const ERROR_INVALID_CONTROL: u8 = 0x06;
+const ERROR_INVALID_REQUEST: u8 = 0x07;
+const ERROR_INVALID_VALUE_WITHIN_RANGE: u8 = 0x08;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codec,
+        control::{ControlId, RequestErrorCode},
+        descriptors::{CameraTerminal, InputTerminal, ProcessingUnit},
+        Error,
+    };
+
+    const IT: u8 = 1;
+    const PU: u8 = 2;
+
+    fn emulator() -> Emulator {
+        let emulator = Emulator::new(VideoControl {
+            input_terminals: vec![InputTerminal {
+                id: IT,
+                terminal_type: 0x0201,
+                assoc_terminal: 0,
+                terminal_string: 0,
+                camera: Some(CameraTerminal {
+                    objective_focal_length_min: 0,
+                    objective_focal_length_max: 0,
+                    ocular_focal_length: 0,
+                    controls: vec![0x00, 0x02, 0x00],
+                }),
+            }],
+            processing_units: vec![ProcessingUnit {
+                id: PU,
+                source_id: IT,
+                max_multiplier: 0,
+                controls: vec![0x41, 0x10],
+                processing_string: 0,
+                video_standards: None,
+            }],
+            ..Default::default()
+        });
+        emulator
+            .control(
+                IT,
+                ControlId::ZoomAbs.selector(),
+                EmulatedControl::new(codec::ZOOM_ABS).range(&[100], &[500], &[10], &[100]),
+            )
+            .control(
+                PU,
+                ControlId::Brightness.selector(),
+                EmulatedControl::new(codec::BRIGHTNESS).range(&[-64], &[64], &[1], &[0]),
+            )
+            .control(
+                PU,
+                ControlId::WhiteBalanceTemperature.selector(),
+                EmulatedControl::new(codec::WHITE_BALANCE_TEMPERATURE).range(
+                    &[2800],
+                    &[6500],
+                    &[1],
+                    &[4600],
+                ),
+            )
+            .control(
+                PU,
+                ControlId::WhiteBalanceTemperatureAuto.selector(),
+                EmulatedControl::new(codec::WHITE_BALANCE_TEMPERATURE_AUTO).range(
+                    &[0],
+                    &[1],
+                    &[1],
+                    &[1],
+                ),
+            )
+            .link_auto(
+                (PU, ControlId::WhiteBalanceTemperatureAuto.selector()),
+                (PU, ControlId::WhiteBalanceTemperature.selector()),
+                |cur| cur[0] != 0,
+            );
+        emulator
+    }
+
+    #[test]
+    fn caps_get_set() {
+        let emulator = emulator();
+        let device = emulator.device(0);
+
+        let caps = device.zoom_abs_caps().unwrap();
+        assert_eq!(
+            (caps.min, caps.max, caps.res, caps.def),
+            (100, 500, 10, 100)
+        );
+        let caps = device.brightness_caps().unwrap();
+        assert_eq!((caps.min, caps.max, caps.res, caps.def), (-64, 64, 1, 0));
+
+        device.zoom_abs_set(250).unwrap();
+        device.brightness_set(-10).unwrap();
+        assert_eq!(device.zoom_abs().unwrap(), 250);
+        assert_eq!(device.brightness().unwrap(), -10);
+        assert_eq!(
+            emulator.cur(IT, ControlId::ZoomAbs.selector()),
+            Some(vec![0xfa, 0x00])
+        );
+        assert_eq!(device.len(ControlId::ZoomAbs).unwrap(), 2);
+        assert_eq!(emulator.error_code(), 0);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let emulator = emulator();
+        let device = emulator.device(0);
+
+        assert!(matches!(
+            device.zoom_abs_set(510),
+            Err(Error::Uvc(RequestErrorCode::OutOfRange))
+        ));
+        assert!(matches!(
+            device.brightness_set(-65),
+            Err(Error::Uvc(RequestErrorCode::OutOfRange))
+        ));
+        assert_eq!(device.zoom_abs().unwrap(), 100);
+        assert_eq!(device.brightness().unwrap(), 0);
+    }
+
+    #[test]
+    fn off_resolution() {
+        let emulator = emulator();
+        let device = emulator.device(0);
+
+        assert!(matches!(
+            device.zoom_abs_set(105),
+            Err(Error::Uvc(RequestErrorCode::InvalidValueWithinRange))
+        ));
+        assert_eq!(device.zoom_abs().unwrap(), 100);
+
+        emulator.quirks(Quirks {
+            ignore_res: true,
+            ..Default::default()
+        });
+        device.zoom_abs_set(105).unwrap();
+        assert_eq!(device.zoom_abs().unwrap(), 105);
+    }
+
+    #[test]
+    fn clamp_quirk() {
+        let emulator = emulator();
+        emulator.quirks(Quirks {
+            clamp_out_of_range: true,
+            ..Default::default()
+        });
+        let device = emulator.device(0);
+
+        device.zoom_abs_set(1000).unwrap();
+        assert_eq!(device.zoom_abs().unwrap(), 500);
+        device.brightness_set(-100).unwrap();
+        assert_eq!(device.brightness().unwrap(), -64);
+    }
+
+    #[test]
+    fn no_error_code_control_quirk() {
+        let emulator = emulator();
+        emulator.quirks(Quirks {
+            no_error_code_control: true,
+            ..Default::default()
+        });
+        let device = emulator.device(0);
+
+        assert!(matches!(
+            device.zoom_abs_set(510),
+            Err(Error::UbsTransfer(TransferError::Stall))
+        ));
+    }
+
+    #[test]
+    fn unknown_controls_and_units() {
+        let emulator = emulator();
+        let device = emulator.device(0);
+
+        assert!(matches!(
+            device.gain(),
+            Err(Error::Uvc(RequestErrorCode::InvalidControl))
+        ));
+        assert!(matches!(
+            device.get_raw::<2>(Request::GetCur, ControlId::ZoomAbs.selector(), 9),
+            Err(Error::Uvc(RequestErrorCode::InvalidUnit))
+        ));
+    }
+
+    #[test]
+    fn read_only_control() {
+        let emulator = emulator();
+        emulator.control(
+            PU,
+            ControlId::Gain.selector(),
+            EmulatedControl::new(codec::GAIN).info(INFO_GET),
+        );
+        let device = emulator.device(0);
+
+        assert_eq!(device.gain().unwrap(), 0);
+        assert!(!device.info(ControlId::Gain).unwrap().set);
+        assert!(matches!(
+            device.gain_set(1),
+            Err(Error::Uvc(RequestErrorCode::InvalidRequest))
+        ));
+    }
+
+    #[test]
+    fn disabled_by_auto_mode() {
+        let emulator = emulator();
+        let device = emulator.device(0);
+
+        assert!(
+            device
+                .info(ControlId::WhiteBalanceTemperature)
+                .unwrap()
+                .disabled_auto
+        );
+        assert!(matches!(
+            device.white_balance_temperature_set(3000),
+            Err(Error::Uvc(RequestErrorCode::WrongState))
+        ));
+
+        device.white_balance_temperature_auto_set(false).unwrap();
+        assert!(device
+            .info(ControlId::WhiteBalanceTemperature)
+            .unwrap()
+            .writable());
+        device.white_balance_temperature_set(3000).unwrap();
+        assert_eq!(device.white_balance_temperature().unwrap(), 3000);
+    }
+
+    #[test]
+    fn changed_by_device() {
+        let emulator = emulator();
+        let device = emulator.device(0);
+
+        emulator.set_cur(PU, ControlId::Brightness.selector(), &[0x20, 0x00]);
+        assert_eq!(device.brightness().unwrap(), 32);
+    }
+}
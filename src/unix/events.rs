@@ -0,0 +1,236 @@
+use super::Stopper;
+use crate::Error;
+use nusb::transfer::{self, TransferError};
+use std::{future::Future, pin::Pin};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Attribute {
+    Value,
+    Info,
+    Failure,
+    Min,
+    Max,
+    Unknown(u8),
+}
+
+impl From<u8> for Attribute {
+    fn from(v: u8) -> Self {
+        match v {
+            0x00 => Attribute::Value,
+            0x01 => Attribute::Info,
+            0x02 => Attribute::Failure,
+            0x03 => Attribute::Min,
+            0x04 => Attribute::Max,
+            v => Attribute::Unknown(v),
+        }
+    }
+}
+
+/// A status packet sent by the camera on the VideoControl interrupt endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A control of a terminal or unit changed, e.g. an autoupdate value or the completion of
+    /// an asynchronous SET_CUR.
+    Control {
+        originator: u8,
+        selector: u8,
+        attribute: Attribute,
+        value: Vec<u8>,
+    },
+    /// A hardware button of a VideoStreaming interface was pressed or released.
+    Button { interface: u8, pressed: bool },
+    Streaming {
+        interface: u8,
+        event: u8,
+        value: Vec<u8>,
+    },
+}
+
+impl Event {
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        match *data {
+            // bStatusType, bOriginator, bEvent, bSelector, bAttribute, bValue
+            [0x01, originator, 0x00, selector, attribute, ref value @ ..] => Some(Event::Control {
+                originator,
+                selector,
+                attribute: attribute.into(),
+                value: value.to_vec(),
+            }),
+            // bStatusType, bOriginator, bEvent, bValue
+            [0x02, interface, 0x00, value, ..] => Some(Event::Button {
+                interface,
+                pressed: value != 0,
+            }),
+            [0x02, interface, event, ref value @ ..] => Some(Event::Streaming {
+                interface,
+                event,
+                value: value.to_vec(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Number of transfers kept in flight so that packets arriving while the consumer is busy are
+/// not dropped.
+const TRANSFERS: usize = 3;
+
+/// Blocks on the status interrupt endpoint, yielding every packet that parses as an `Event`.
+///
+/// A failed transfer is yielded as an error and replaced. Once the device is disconnected the
+/// iterator ends.
+pub struct Events {
+    _inf: nusb::Interface,
+    queue: transfer::Queue<transfer::RequestBuffer>,
+    packet_size: usize,
+    stopper: Stopper,
+    done: bool,
+}
+
+impl Events {
+    pub(super) fn new(inf: nusb::Interface, ep: u8, packet_size: usize) -> Self {
+        let mut queue = inf.interrupt_in_queue(ep);
+        for _ in 0..TRANSFERS {
+            queue.submit(transfer::RequestBuffer::new(packet_size));
+        }
+        Events {
+            _inf: inf,
+            queue,
+            packet_size,
+            stopper: Stopper::default(),
+            done: false,
+        }
+    }
+
+    pub fn stopper(&self) -> Stopper {
+        self.stopper.clone()
+    }
+}
+
+impl Iterator for Events {
+    type Item = Result<Event, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+
+            let queue = &mut self.queue;
+            let completion = self
+                .stopper
+                .block_on(|cx| Pin::new(&mut queue.next_complete()).poll(cx))?;
+
+            let r = match completion.status {
+                Ok(()) => Event::parse(&completion.data).map(Ok),
+                Err(TransferError::Disconnected) => {
+                    self.done = true;
+                    Some(Err(TransferError::Disconnected.into()))
+                }
+                Err(err) => Some(Err(err.into())),
+            };
+            // Keep the queue full, `next_complete` panics once nothing is pending.
+            if !self.done {
+                self.queue.submit(transfer::RequestBuffer::reuse(
+                    completion.data,
+                    self.packet_size,
+                ));
+            }
+
+            if r.is_some() {
+                return r;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control() {
+        assert_eq!(
+            Event::parse(&[0x01, 0x02, 0x00, 0x02, 0x00, 0x34, 0x12]),
+            Some(Event::Control {
+                originator: 2,
+                selector: 0x02,
+                attribute: Attribute::Value,
+                value: vec![0x34, 0x12],
+            })
+        );
+        assert_eq!(
+            Event::parse(&[0x01, 0x01, 0x00, 0x0b, 0x02, 0x07]),
+            Some(Event::Control {
+                originator: 1,
+                selector: 0x0b,
+                attribute: Attribute::Failure,
+                value: vec![0x07],
+            })
+        );
+        assert_eq!(
+            Event::parse(&[0x01, 0x01, 0x00, 0x0b, 0x09]),
+            Some(Event::Control {
+                originator: 1,
+                selector: 0x0b,
+                attribute: Attribute::Unknown(0x09),
+                value: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn button() {
+        assert_eq!(
+            Event::parse(&[0x02, 0x01, 0x00, 0x01]),
+            Some(Event::Button {
+                interface: 1,
+                pressed: true,
+            })
+        );
+        assert_eq!(
+            Event::parse(&[0x02, 0x01, 0x00, 0x00]),
+            Some(Event::Button {
+                interface: 1,
+                pressed: false,
+            })
+        );
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(
+            Event::parse(&[0x02, 0x01, 0x05, 0xaa]),
+            Some(Event::Streaming {
+                interface: 1,
+                event: 0x05,
+                value: vec![0xaa],
+            })
+        );
+        // A button event without bValue is not a button press.
+        assert_eq!(
+            Event::parse(&[0x02, 0x01, 0x00]),
+            Some(Event::Streaming {
+                interface: 1,
+                event: 0x00,
+                value: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn short() {
+        assert_eq!(Event::parse(&[]), None);
+        assert_eq!(Event::parse(&[0x01]), None);
+        assert_eq!(Event::parse(&[0x01, 0x02, 0x00, 0x02]), None);
+        assert_eq!(Event::parse(&[0x02, 0x01]), None);
+    }
+
+    #[test]
+    fn unknown_status_type() {
+        assert_eq!(Event::parse(&[0x00, 0x01, 0x00, 0x01, 0x00, 0x00]), None);
+        assert_eq!(Event::parse(&[0x03, 0x01, 0x00, 0x01, 0x00, 0x00]), None);
+        // VideoControl events other than a control change are not defined.
+        assert_eq!(Event::parse(&[0x01, 0x02, 0x01, 0x02, 0x00, 0x00]), None);
+    }
+}
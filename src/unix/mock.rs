@@ -0,0 +1,132 @@
+use super::{Request, Transport};
+use nusb::transfer::TransferError;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// A scriptable [`Transport`] that records every transfer and answers with canned responses.
+///
+/// Clones share the same script and log, so a handle can be kept after moving one into a
+/// [`Device`](super::Device).
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    pub request: u8,
+    pub value: u16,
+    pub index: u16,
+    pub data: Vec<u8>,
+}
+
+impl Transfer {
+    pub fn selector(&self) -> u8 {
+        (self.value >> 8) as _
+    }
+
+    pub fn unit(&self) -> u8 {
+        (self.index >> 8) as _
+    }
+}
+
+#[derive(Default)]
+struct State {
+    responses: HashMap<(u8, u8, u8), Result<Vec<u8>, TransferError>>,
+    transfers: Vec<Transfer>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers every `req` for `selector` on `unit` with `data`.
+    pub fn respond(&self, req: Request, selector: u8, unit: u8, data: &[u8]) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert((req as _, selector, unit), Ok(data.to_vec()));
+        self
+    }
+
+    /// Fails every `req` for `selector` on `unit` with `err`.
+    pub fn fail(&self, req: Request, selector: u8, unit: u8, err: TransferError) -> &Self {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert((req as _, selector, unit), Err(err));
+        self
+    }
+
+    pub fn transfers(&self) -> Vec<Transfer> {
+        self.state.lock().unwrap().transfers.clone()
+    }
+
+    pub fn clear_transfers(&self) {
+        self.state.lock().unwrap().transfers.clear();
+    }
+}
+
+impl Transport for MockTransport {
+    fn control_in(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &mut [u8],
+        _timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        let mut state = self.state.lock().unwrap();
+        state.transfers.push(Transfer {
+            request,
+            value,
+            index,
+            data: vec![],
+        });
+
+        // Unscripted requests behave like a control the device does not implement.
+        match state
+            .responses
+            .get(&(request, (value >> 8) as _, (index >> 8) as _))
+        {
+            Some(Ok(r)) => {
+                let len = r.len().min(data.len());
+                data[..len].copy_from_slice(&r[..len]);
+                Ok(len)
+            }
+            Some(Err(err)) => Err(*err),
+            None => Err(TransferError::Stall),
+        }
+    }
+
+    fn control_out(
+        &self,
+        request: u8,
+        value: u16,
+        index: u16,
+        data: &[u8],
+        _timeout: Duration,
+    ) -> Result<usize, TransferError> {
+        let mut state = self.state.lock().unwrap();
+        state.transfers.push(Transfer {
+            request,
+            value,
+            index,
+            data: data.to_vec(),
+        });
+
+        match state
+            .responses
+            .get(&(request, (value >> 8) as _, (index >> 8) as _))
+        {
+            Some(Err(err)) => Err(*err),
+            _ => Ok(data.len()),
+        }
+    }
+}
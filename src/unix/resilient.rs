@@ -0,0 +1,66 @@
+use super::{Device, DeviceInfo};
+use crate::{control::ControlId, Error};
+use nusb::transfer::TransferError;
+use std::sync::{Mutex, RwLock};
+
+/// A `Device` that survives the camera being unplugged and plugged back in.
+///
+/// When a call fails because the device is gone, the same camera is looked up again, reopened
+/// with the same transfer policy, the last value set on each absolute control is re-applied, and
+/// the call is retried once. Values that fail to re-apply stay in the history; call `reconnect`
+/// directly to find out which ones they were.
+pub struct ResilientDevice {
+    info: Mutex<DeviceInfo>,
+    device: RwLock<Device>,
+}
+
+impl ResilientDevice {
+    pub fn new(info: DeviceInfo) -> Result<Self, Error> {
+        let device = info.open()?;
+        Ok(ResilientDevice {
+            info: Mutex::new(info),
+            device: RwLock::new(device),
+        })
+    }
+
+    pub fn with_device<R>(&self, f: impl Fn(&Device) -> Result<R, Error>) -> Result<R, Error> {
+        // Bound first so the read guard is released before reconnecting.
+        let r = f(&self.device.read().unwrap());
+        match r {
+            Err(err) if is_disconnected(&err) => {
+                self.reconnect()?;
+                f(&self.device.read().unwrap())
+            }
+            r => r,
+        }
+    }
+
+    /// Reopens the camera and re-applies the history. The camera is reconnected even when some
+    /// values fail to re-apply; those are returned with their errors.
+    pub fn reconnect(&self) -> Result<Vec<(ControlId, Error)>, Error> {
+        let mut device = self.device.write().unwrap();
+        let mut info = self.info.lock().unwrap();
+
+        let Some(found) = DeviceInfo::enumerate()?
+            .into_iter()
+            .find(|i| i.same_device(&info))
+        else {
+            return Err(Error::DeviceNotFound);
+        };
+        let reopened = found.open_with(device.policy().clone())?;
+
+        // Controls can be rejected while e.g. an auto mode is being restored; keep going.
+        let errors = reopened.replay(device.applied());
+
+        *device = reopened;
+        *info = found;
+        Ok(errors)
+    }
+}
+
+fn is_disconnected(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::Usb(_) | Error::UbsTransfer(TransferError::Disconnected)
+    )
+}
@@ -0,0 +1,41 @@
+use futures_lite::future;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+/// Ends a blocking iterator, possibly from another thread while it is blocked.
+#[derive(Clone, Default)]
+pub struct Stopper(Arc<State>);
+
+#[derive(Default)]
+struct State {
+    stopped: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Stopper {
+    pub fn stop(&self) {
+        self.0.stopped.store(true, Ordering::SeqCst);
+        if let Some(waker) = self.0.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Blocks until `poll` is ready, or returns `None` once stopped.
+    pub(crate) fn block_on<T>(
+        &self,
+        mut poll: impl FnMut(&mut Context<'_>) -> Poll<T>,
+    ) -> Option<T> {
+        future::block_on(future::poll_fn(|cx| {
+            *self.0.waker.lock().unwrap() = Some(cx.waker().clone());
+            if self.0.stopped.load(Ordering::SeqCst) {
+                return Poll::Ready(None);
+            }
+            poll(cx).map(Some)
+        }))
+    }
+}
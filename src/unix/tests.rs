@@ -0,0 +1,1025 @@
+use super::{
+    mock::{MockTransport, Transfer},
+    Device, Request, TransferPolicy,
+};
+use crate::{
+    control::{AeMode, ControlId, FocusSimpleRange, PowerLineFrequency, ScanningMode, UnitKind},
+    descriptors::{CameraTerminal, InputTerminal, ProcessingUnit, VideoControl},
+    Caps, Error, RequestErrorCode,
+};
+use nusb::transfer::TransferError;
+use std::time::Duration;
+
+const INF: u8 = 1;
+const IT: u8 = 2;
+const PU: u8 = 3;
+
+fn device() -> (MockTransport, Device<MockTransport>) {
+    let descriptors = VideoControl {
+        input_terminals: vec![InputTerminal {
+            id: IT,
+            terminal_type: 0x0201,
+            assoc_terminal: 0,
+            terminal_string: 0,
+            camera: Some(CameraTerminal {
+                objective_focal_length_min: 0,
+                objective_focal_length_max: 0,
+                ocular_focal_length: 0,
+                controls: vec![0xff, 0xff, 0xff],
+            }),
+        }],
+        processing_units: vec![ProcessingUnit {
+            id: PU,
+            source_id: IT,
+            max_multiplier: 0,
+            controls: vec![0xff, 0xff, 0xff],
+            processing_string: 0,
+            video_standards: None,
+        }],
+        ..Default::default()
+    };
+    let mock = MockTransport::new();
+    (mock.clone(), Device::with_transport(mock, INF, descriptors))
+}
+
+fn unit(id: ControlId) -> u8 {
+    match id.unit() {
+        UnitKind::CameraTerminal => IT,
+        UnitKind::ProcessingUnit => PU,
+    }
+}
+
+fn transfer(req: Request, id: ControlId, data: &[u8]) -> Transfer {
+    Transfer {
+        request: req as _,
+        value: (id.selector() as u16) << 8,
+        index: (unit(id) as u16) << 8 | INF as u16,
+        data: data.to_vec(),
+    }
+}
+
+fn respond(mock: &MockTransport, req: Request, id: ControlId, data: &[u8]) {
+    mock.respond(req, id.selector(), unit(id), data);
+}
+
+fn respond_caps(mock: &MockTransport, id: ControlId, caps: [&[u8]; 4]) {
+    respond(mock, Request::GetMin, id, caps[0]);
+    respond(mock, Request::GetMax, id, caps[1]);
+    respond(mock, Request::GetRes, id, caps[2]);
+    respond(mock, Request::GetDef, id, caps[3]);
+}
+
+fn caps_transfers(id: ControlId) -> Vec<Transfer> {
+    [
+        Request::GetMin,
+        Request::GetMax,
+        Request::GetRes,
+        Request::GetDef,
+    ]
+    .into_iter()
+    .map(|req| transfer(req, id, &[]))
+    .collect()
+}
+
+fn assert_caps(caps: Caps, min: i32, max: i32, res: i32, def: i32) {
+    assert_eq!(
+        (caps.min, caps.max, caps.res, caps.def),
+        (min, max, res, def)
+    );
+}
+
+type CapsFn = fn(&Device<MockTransport>) -> Result<Caps, Error>;
+type GetFn = fn(&Device<MockTransport>) -> Result<i32, Error>;
+type SetFn = fn(&Device<MockTransport>, i32) -> Result<(), Error>;
+
+#[test]
+fn two_byte_scalar_controls() {
+    let controls: &[(ControlId, CapsFn, GetFn, SetFn)] = &[
+        (
+            ControlId::ZoomAbs,
+            Device::zoom_abs_caps,
+            Device::zoom_abs,
+            Device::zoom_abs_set,
+        ),
+        (
+            ControlId::FocusAbs,
+            Device::focus_abs_caps,
+            Device::focus_abs,
+            Device::focus_abs_set,
+        ),
+        (
+            ControlId::IrisAbs,
+            Device::iris_abs_caps,
+            Device::iris_abs,
+            Device::iris_abs_set,
+        ),
+        (
+            ControlId::RollAbs,
+            Device::roll_abs_caps,
+            Device::roll_abs,
+            Device::roll_abs_set,
+        ),
+        (
+            ControlId::Brightness,
+            Device::brightness_caps,
+            Device::brightness,
+            Device::brightness_set,
+        ),
+        (
+            ControlId::Contrast,
+            Device::contrast_caps,
+            Device::contrast,
+            Device::contrast_set,
+        ),
+        (
+            ControlId::Hue,
+            Device::hue_caps,
+            Device::hue,
+            Device::hue_set,
+        ),
+        (
+            ControlId::Saturation,
+            Device::saturation_caps,
+            Device::saturation,
+            Device::saturation_set,
+        ),
+        (
+            ControlId::Sharpness,
+            Device::sharpness_caps,
+            Device::sharpness,
+            Device::sharpness_set,
+        ),
+        (
+            ControlId::Gamma,
+            Device::gamma_caps,
+            Device::gamma,
+            Device::gamma_set,
+        ),
+        (
+            ControlId::Gain,
+            Device::gain_caps,
+            Device::gain,
+            Device::gain_set,
+        ),
+        (
+            ControlId::WhiteBalanceTemperature,
+            Device::white_balance_temperature_caps,
+            Device::white_balance_temperature,
+            Device::white_balance_temperature_set,
+        ),
+        (
+            ControlId::BacklightCompensation,
+            Device::backlight_compensation_caps,
+            Device::backlight_compensation,
+            Device::backlight_compensation_set,
+        ),
+        (
+            ControlId::DigitalMultiplier,
+            Device::digital_multiplier_caps,
+            Device::digital_multiplier,
+            Device::digital_multiplier_set,
+        ),
+        (
+            ControlId::DigitalMultiplierLimit,
+            Device::digital_multiplier_limit_caps,
+            Device::digital_multiplier_limit,
+            Device::digital_multiplier_limit_set,
+        ),
+    ];
+
+    for &(id, caps, get, set) in controls {
+        let (mock, device) = device();
+        respond_caps(
+            &mock,
+            id,
+            [&[0x01, 0x00], &[0x10, 0x27], &[0x01, 0x00], &[0x88, 0x13]],
+        );
+        respond(&mock, Request::GetCur, id, &[0x02, 0x01]);
+
+        assert_caps(caps(&device).unwrap(), 1, 10000, 1, 5000);
+        assert_eq!(mock.transfers(), caps_transfers(id), "{id:?}");
+
+        mock.clear_transfers();
+        assert_eq!(get(&device).unwrap(), 0x0102, "{id:?}");
+        assert_eq!(
+            mock.transfers(),
+            [transfer(Request::GetCur, id, &[])],
+            "{id:?}"
+        );
+
+        mock.clear_transfers();
+        set(&device, 0x0304).unwrap();
+        assert_eq!(
+            mock.transfers().last(),
+            Some(&transfer(Request::SetCur, id, &[0x04, 0x03])),
+            "{id:?}"
+        );
+    }
+}
+
+#[test]
+fn signed_scalar_controls() {
+    for id in [ControlId::Brightness, ControlId::Hue, ControlId::RollAbs] {
+        let (mock, device) = device();
+        respond(&mock, Request::GetCur, id, &[0xfb, 0xff]);
+        assert_eq!(device.get(id).unwrap().0, [-5]);
+
+        device.set(id, (-300).into()).unwrap();
+        assert_eq!(
+            mock.transfers().last(),
+            Some(&transfer(Request::SetCur, id, &[0xd4, 0xfe]))
+        );
+    }
+}
+
+type BoolGetFn = fn(&Device<MockTransport>) -> Result<bool, Error>;
+type BoolSetFn = fn(&Device<MockTransport>, bool) -> Result<(), Error>;
+
+#[test]
+fn bool_controls() {
+    let controls: &[(ControlId, BoolGetFn, BoolSetFn)] = &[
+        (
+            ControlId::AePriority,
+            Device::ae_priority,
+            Device::ae_priority_set,
+        ),
+        (
+            ControlId::FocusAuto,
+            Device::focus_auto,
+            Device::focus_auto_set,
+        ),
+        (ControlId::Privacy, Device::privacy, Device::privacy_set),
+        (ControlId::HueAuto, Device::hue_auto, Device::hue_auto_set),
+        (
+            ControlId::ContrastAuto,
+            Device::contrast_auto,
+            Device::contrast_auto_set,
+        ),
+        (
+            ControlId::WhiteBalanceTemperatureAuto,
+            Device::white_balance_temperature_auto,
+            Device::white_balance_temperature_auto_set,
+        ),
+        (
+            ControlId::WhiteBalanceComponentAuto,
+            Device::white_balance_component_auto,
+            Device::white_balance_component_auto_set,
+        ),
+    ];
+
+    for &(id, get, set) in controls {
+        let (mock, device) = device();
+        respond(&mock, Request::GetCur, id, &[0x01]);
+        assert!(get(&device).unwrap(), "{id:?}");
+
+        set(&device, true).unwrap();
+        set(&device, false).unwrap();
+        assert_eq!(
+            mock.transfers(),
+            [
+                transfer(Request::GetCur, id, &[]),
+                transfer(Request::SetCur, id, &[0x01]),
+                transfer(Request::SetCur, id, &[0x00]),
+            ],
+            "{id:?}"
+        );
+    }
+}
+
+#[test]
+fn pan_tilt_abs() {
+    let id = ControlId::PanTiltAbs;
+    let (mock, device) = device();
+    // pan -3600, tilt 7200
+    respond(
+        &mock,
+        Request::GetCur,
+        id,
+        &[0xf0, 0xf1, 0xff, 0xff, 0x20, 0x1c, 0x00, 0x00],
+    );
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0x60, 0x79, 0xfe, 0xff, 0x60, 0xab, 0xff, 0xff],
+            &[0xa0, 0x86, 0x01, 0x00, 0xa0, 0x54, 0x00, 0x00],
+            &[0x10, 0x0e, 0x00, 0x00, 0x10, 0x0e, 0x00, 0x00],
+            &[0; 8],
+        ],
+    );
+
+    assert_caps(device.pan_abs_caps().unwrap(), -100000, 100000, 3600, 0);
+    assert_caps(device.tilt_abs_caps().unwrap(), -21664, 21664, 3600, 0);
+    assert_eq!(device.pan_abs().unwrap(), -3600);
+    assert_eq!(device.tilt_abs().unwrap(), 7200);
+
+    mock.clear_transfers();
+    device.pan_abs_set(3600).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetCur, id, &[]),
+            transfer(
+                Request::SetCur,
+                id,
+                &[0x10, 0x0e, 0x00, 0x00, 0x20, 0x1c, 0x00, 0x00]
+            ),
+        ]
+    );
+
+    mock.clear_transfers();
+    device.tilt_abs_set(-1).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetCur, id, &[]),
+            transfer(
+                Request::SetCur,
+                id,
+                &[0xf0, 0xf1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn pan_tilt_rel() {
+    let id = ControlId::PanTiltRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x01, 0x05, 0xff, 0x03]);
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0xff, 0x02, 0xff, 0x02],
+            &[0x01, 0x10, 0x01, 0x20],
+            &[0x01, 0x01, 0x01, 0x02],
+            &[0x00, 0x01, 0x00, 0x02],
+        ],
+    );
+
+    assert_caps(device.pan_rel_caps().unwrap(), -1, 1, 1, 0);
+    assert_caps(device.tilt_rel_caps().unwrap(), -1, 1, 1, 0);
+    assert_eq!(device.pan_rel().unwrap(), 1);
+    assert_eq!(device.tilt_rel().unwrap(), -1);
+
+    mock.clear_transfers();
+    device.pan_rel_set(1).unwrap();
+    let mut expected = caps_transfers(id);
+    expected.extend([
+        transfer(Request::GetCur, id, &[]),
+        transfer(Request::SetCur, id, &[0x01, 0x02, 0xff, 0x03]),
+    ]);
+    assert_eq!(mock.transfers(), expected);
+}
+
+#[test]
+fn zoom_rel() {
+    let id = ControlId::ZoomRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0xff, 0x00, 0x02]);
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0xff, 0x00, 0x02],
+            &[0x01, 0x01, 0x07],
+            &[0x01, 0x01, 0x01],
+            &[0x00, 0x00, 0x01],
+        ],
+    );
+
+    assert_caps(device.zoom_rel_caps().unwrap(), -1, 1, 1, 0);
+    assert_eq!(device.zoom_rel().unwrap(), -1);
+
+    mock.clear_transfers();
+    device.zoom_rel_set(-2).unwrap();
+    let mut expected = caps_transfers(id);
+    expected.push(transfer(Request::SetCur, id, &[0xff, 0x00, 0x02]));
+    assert_eq!(mock.transfers(), expected);
+}
+
+#[test]
+fn white_balance_component() {
+    let id = ControlId::WhiteBalanceComponent;
+    let (mock, device) = device();
+    // blue 0x0100, red 0x0200
+    respond(&mock, Request::GetCur, id, &[0x00, 0x01, 0x00, 0x02]);
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0x00, 0x00, 0x01, 0x00],
+            &[0xff, 0x0f, 0xfe, 0x0f],
+            &[0x01, 0x00, 0x02, 0x00],
+            &[0x00, 0x08, 0x00, 0x04],
+        ],
+    );
+
+    assert_caps(device.white_balance_blue_caps().unwrap(), 0, 4095, 1, 2048);
+    assert_caps(device.white_balance_red_caps().unwrap(), 1, 4094, 2, 1024);
+    assert_eq!(device.white_balance_blue().unwrap(), 0x0100);
+    assert_eq!(device.white_balance_red().unwrap(), 0x0200);
+
+    mock.clear_transfers();
+    device.white_balance_blue_set(0x0304).unwrap();
+    device.white_balance_red_set(0x0506).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetCur, id, &[]),
+            transfer(Request::SetCur, id, &[0x04, 0x03, 0x00, 0x02]),
+            transfer(Request::GetCur, id, &[]),
+            transfer(Request::SetCur, id, &[0x00, 0x01, 0x06, 0x05]),
+        ]
+    );
+
+    mock.clear_transfers();
+    device.white_balance_component_set(0x0102, 0x0304).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [transfer(Request::SetCur, id, &[0x02, 0x01, 0x04, 0x03])]
+    );
+}
+
+#[test]
+fn exposure_time_abs() {
+    let id = ControlId::ExposureTimeAbs;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x10, 0x27, 0x00, 0x00]);
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0x01, 0x00, 0x00, 0x00],
+            &[0x40, 0x0d, 0x03, 0x00],
+            &[0x01, 0x00, 0x00, 0x00],
+            &[0x9c, 0x00, 0x00, 0x00],
+        ],
+    );
+
+    assert_caps(device.exposure_time_abs_caps().unwrap(), 1, 200000, 1, 156);
+    assert_eq!(device.exposure_time_abs().unwrap(), Duration::from_secs(1));
+
+    mock.clear_transfers();
+    device
+        .exposure_time_abs_set(Duration::from_millis(20))
+        .unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [transfer(Request::SetCur, id, &[0xc8, 0x00, 0x00, 0x00])]
+    );
+}
+
+#[test]
+fn exposure_time_rel() {
+    let id = ControlId::ExposureTimeRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0xff]);
+    assert_eq!(device.exposure_time_rel().unwrap(), -1);
+
+    mock.clear_transfers();
+    device.exposure_time_rel_set(1).unwrap();
+    device.exposure_time_rel_set(-1).unwrap();
+    device.exposure_time_rel_set(5).unwrap();
+    device.exposure_time_rel_set(-300).unwrap();
+    device.exposure_time_rel_set(0).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::SetCur, id, &[0x01]),
+            transfer(Request::SetCur, id, &[0xff]),
+            transfer(Request::SetCur, id, &[0x01]),
+            transfer(Request::SetCur, id, &[0xff]),
+            transfer(Request::SetCur, id, &[0x00]),
+        ]
+    );
+}
+
+#[test]
+fn ae_mode() {
+    let id = ControlId::AeMode;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x08]);
+    respond(&mock, Request::GetRes, id, &[0x09]);
+
+    assert_eq!(
+        device.ae_modes().unwrap(),
+        [AeMode::Manual, AeMode::AperturePriority]
+    );
+    assert_eq!(device.ae_mode().unwrap(), AeMode::AperturePriority);
+
+    mock.clear_transfers();
+    assert!(matches!(
+        device.ae_mode_set(AeMode::Auto),
+        Err(Error::InvalidValue)
+    ));
+    device.ae_mode_set(AeMode::Manual).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetRes, id, &[]),
+            transfer(Request::GetRes, id, &[]),
+            transfer(Request::SetCur, id, &[0x01]),
+        ]
+    );
+}
+
+#[test]
+fn focus_rel() {
+    let id = ControlId::FocusRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0xff, 0x02]);
+    respond_caps(
+        &mock,
+        id,
+        [&[0xff, 0x01], &[0x01, 0x0a], &[0x01, 0x01], &[0x00, 0x01]],
+    );
+
+    assert_caps(device.focus_rel_speed_caps().unwrap(), 1, 10, 1, 1);
+    assert_eq!(device.focus_rel().unwrap(), -1);
+
+    mock.clear_transfers();
+    device.focus_rel_set(-9, 4).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [transfer(Request::SetCur, id, &[0xff, 0x04])]
+    );
+}
+
+#[test]
+fn focus_simple_range() {
+    let id = ControlId::FocusSimple;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x02]);
+    assert_eq!(
+        device.focus_simple_range().unwrap(),
+        FocusSimpleRange::People
+    );
+
+    mock.clear_transfers();
+    device
+        .focus_simple_range_set(FocusSimpleRange::Macro)
+        .unwrap();
+    assert_eq!(mock.transfers(), [transfer(Request::SetCur, id, &[0x01])]);
+}
+
+#[test]
+fn iris_rel() {
+    let id = ControlId::IrisRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0xff]);
+    assert_eq!(device.iris_rel().unwrap(), -1);
+
+    mock.clear_transfers();
+    device.iris_rel_set(-3).unwrap();
+    device.iris_rel_set(0).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::SetCur, id, &[0xff]),
+            transfer(Request::SetCur, id, &[0x00]),
+        ]
+    );
+}
+
+#[test]
+fn roll_rel() {
+    let id = ControlId::RollRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x01, 0x03]);
+    respond_caps(
+        &mock,
+        id,
+        [&[0xff, 0x02], &[0x01, 0x0b], &[0x01, 0x01], &[0x00, 0x02]],
+    );
+
+    assert_caps(device.roll_rel_speed_caps().unwrap(), 2, 11, 1, 2);
+    assert_eq!(device.roll_rel().unwrap(), 1);
+
+    mock.clear_transfers();
+    device.roll_rel_set(2, 5).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [transfer(Request::SetCur, id, &[0x01, 0x05])]
+    );
+}
+
+#[test]
+fn scanning_mode() {
+    let id = ControlId::ScanningMode;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x01]);
+    assert_eq!(device.scanning_mode().unwrap(), ScanningMode::Progressive);
+
+    mock.clear_transfers();
+    device.scanning_mode_set(ScanningMode::Interlaced).unwrap();
+    assert_eq!(mock.transfers(), [transfer(Request::SetCur, id, &[0x00])]);
+}
+
+#[test]
+fn power_line_frequency() {
+    let id = ControlId::PowerLineFrequency;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x01]);
+    respond_caps(&mock, id, [&[0x00], &[0x02], &[0x01], &[0x02]]);
+    assert_eq!(
+        device.power_line_frequency().unwrap(),
+        PowerLineFrequency::Hz50
+    );
+
+    mock.clear_transfers();
+    assert!(matches!(
+        device.power_line_frequency_set(PowerLineFrequency::Auto),
+        Err(Error::InvalidValue)
+    ));
+    device
+        .power_line_frequency_set(PowerLineFrequency::Hz60)
+        .unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetMin, id, &[]),
+            transfer(Request::GetMax, id, &[]),
+            transfer(Request::GetMin, id, &[]),
+            transfer(Request::GetMax, id, &[]),
+            transfer(Request::SetCur, id, &[0x02]),
+        ]
+    );
+}
+
+#[test]
+fn power_line_frequency_without_range() {
+    let id = ControlId::PowerLineFrequency;
+    let (mock, device) = device();
+    mock.fail(Request::GetMin, id.selector(), PU, TransferError::Stall);
+
+    device
+        .power_line_frequency_set(PowerLineFrequency::Auto)
+        .unwrap();
+    assert_eq!(
+        mock.transfers().last(),
+        Some(&transfer(Request::SetCur, id, &[0x03]))
+    );
+
+    mock.fail(Request::SetCur, id.selector(), PU, TransferError::Stall);
+    mock.respond(Request::GetCur, 0x02, 0, &[0x04]);
+    assert!(matches!(
+        device.power_line_frequency_set(PowerLineFrequency::Auto),
+        Err(Error::Uvc(RequestErrorCode::OutOfRange))
+    ));
+}
+
+#[test]
+fn backlight_compensation_out_of_range() {
+    let id = ControlId::BacklightCompensation;
+    let (mock, device) = device();
+    respond_caps(
+        &mock,
+        id,
+        [&[0x00, 0x00], &[0x02, 0x00], &[0x01, 0x00], &[0x01, 0x00]],
+    );
+
+    assert!(matches!(
+        device.backlight_compensation_set(3),
+        Err(Error::InvalidValue)
+    ));
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetMin, id, &[]),
+            transfer(Request::GetMax, id, &[]),
+        ]
+    );
+}
+
+#[test]
+fn info_and_len() {
+    let id = ControlId::ZoomAbs;
+    let (mock, device) = device();
+    respond(&mock, Request::GetInfo, id, &[0x0b]);
+    respond(&mock, Request::GetLen, id, &[0x02, 0x00]);
+
+    let info = device.info(id).unwrap();
+    assert!(info.get && info.set && info.autoupdate);
+    assert!(!info.disabled_auto && !info.asynchronous);
+    assert_eq!(device.len(id).unwrap(), 2);
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetInfo, id, &[]),
+            transfer(Request::GetLen, id, &[]),
+        ]
+    );
+}
+
+#[test]
+fn stall_reads_request_error_code() {
+    let id = ControlId::ZoomAbs;
+    let (mock, device) = device();
+    mock.fail(Request::SetCur, id.selector(), IT, TransferError::Stall);
+    mock.respond(Request::GetCur, 0x02, 0, &[0x04]);
+
+    assert!(matches!(
+        device.zoom_abs_set(1),
+        Err(Error::Uvc(RequestErrorCode::OutOfRange))
+    ));
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::SetCur, id, &[0x01, 0x00]),
+            Transfer {
+                request: Request::GetCur as _,
+                value: 0x02 << 8,
+                index: INF as _,
+                data: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn stall_without_request_error_code() {
+    let (mock, device) = device();
+    mock.fail(
+        Request::GetCur,
+        ControlId::ZoomAbs.selector(),
+        IT,
+        TransferError::Stall,
+    );
+
+    assert!(matches!(
+        device.zoom_abs(),
+        Err(Error::UbsTransfer(TransferError::Stall))
+    ));
+}
+
+#[test]
+fn retries_retryable_errors() {
+    let id = ControlId::Gain;
+    let (mock, mut device) = device();
+    device.set_policy(TransferPolicy {
+        retries: 2,
+        backoff: Duration::ZERO,
+        ..Default::default()
+    });
+    mock.fail(Request::GetCur, id.selector(), PU, TransferError::Fault);
+    mock.fail(
+        Request::SetCur,
+        id.selector(),
+        PU,
+        TransferError::Disconnected,
+    );
+
+    assert!(device.gain().is_err());
+    assert_eq!(
+        mock.transfers(),
+        vec![transfer(Request::GetCur, id, &[]); 3]
+    );
+
+    mock.clear_transfers();
+    assert!(device.gain_set(1).is_err());
+    assert_eq!(
+        mock.transfers(),
+        [transfer(Request::SetCur, id, &[0x01, 0x00])]
+    );
+}
+
+#[test]
+fn pan_tilt_combined() {
+    let (mock, device) = device();
+    // pan -3600, tilt 7200
+    respond(
+        &mock,
+        Request::GetCur,
+        ControlId::PanTiltAbs,
+        &[0xf0, 0xf1, 0xff, 0xff, 0x20, 0x1c, 0x00, 0x00],
+    );
+    assert_eq!(device.pan_tilt_abs().unwrap(), (-3600, 7200));
+
+    mock.clear_transfers();
+    device.pan_tilt_abs_set(1, 2).unwrap();
+    device.pan_tilt_rel_set(1, 2, -7, 3).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(
+                Request::SetCur,
+                ControlId::PanTiltAbs,
+                &[0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]
+            ),
+            transfer(
+                Request::SetCur,
+                ControlId::PanTiltRel,
+                &[0x01, 0x02, 0xff, 0x03]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn pan_tilt_rel_move() {
+    let id = ControlId::PanTiltRel;
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, id, &[0x01, 0x05, 0xff, 0x03]);
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0xff, 0x01, 0xff, 0x02],
+            &[0x01, 0x10, 0x01, 0x20],
+            &[0x01, 0x01, 0x01, 0x02],
+            &[0x00, 0x01, 0x00, 0x02],
+        ],
+    );
+
+    assert_caps(device.pan_rel_speed_caps().unwrap(), 1, 16, 1, 1);
+    assert_caps(device.tilt_rel_speed_caps().unwrap(), 2, 32, 2, 2);
+
+    mock.clear_transfers();
+    device.pan_rel_move(-7, 4).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetCur, id, &[]),
+            transfer(Request::SetCur, id, &[0xff, 0x04, 0xff, 0x03]),
+        ]
+    );
+
+    mock.clear_transfers();
+    device.tilt_rel_move(0, 8).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [
+            transfer(Request::GetCur, id, &[]),
+            transfer(Request::SetCur, id, &[0x01, 0x05, 0x00, 0x08]),
+        ]
+    );
+}
+
+#[test]
+fn zoom_rel_move() {
+    let id = ControlId::ZoomRel;
+    let (mock, device) = device();
+    respond_caps(
+        &mock,
+        id,
+        [
+            &[0xff, 0x00, 0x01],
+            &[0x01, 0x01, 0x07],
+            &[0x01, 0x01, 0x01],
+            &[0x00, 0x00, 0x01],
+        ],
+    );
+
+    assert_caps(device.zoom_rel_speed_caps().unwrap(), 1, 7, 1, 1);
+
+    mock.clear_transfers();
+    device.zoom_rel_move(5, 3).unwrap();
+    assert_eq!(
+        mock.transfers(),
+        [transfer(Request::SetCur, id, &[0x01, 0x00, 0x03])]
+    );
+}
+
+fn zoom_device(limit: Option<&[u8]>) -> (MockTransport, Device<MockTransport>) {
+    let (mock, device) = device();
+    // optical 100..=500, digital multiplier 100..=400
+    respond_caps(
+        &mock,
+        ControlId::ZoomAbs,
+        [&[0x64, 0x00], &[0xf4, 0x01], &[0x01, 0x00], &[0x64, 0x00]],
+    );
+    respond_caps(
+        &mock,
+        ControlId::DigitalMultiplier,
+        [&[0x64, 0x00], &[0x90, 0x01], &[0x01, 0x00], &[0x64, 0x00]],
+    );
+    if let Some(limit) = limit {
+        respond(
+            &mock,
+            Request::GetCur,
+            ControlId::DigitalMultiplierLimit,
+            limit,
+        );
+    }
+    (mock, device)
+}
+
+fn zoom_sets(mock: &MockTransport) -> Vec<Transfer> {
+    mock.transfers()
+        .into_iter()
+        .filter(|t| t.request == Request::SetCur as u8)
+        .collect()
+}
+
+#[test]
+fn zoom_effective_uses_multiplier_limit() {
+    // limit 250
+    let (mock, device) = zoom_device(Some(&[0xfa, 0x00]));
+    assert_caps(device.zoom_effective_caps().unwrap(), 100, 650, 1, 100);
+
+    respond(&mock, Request::GetCur, ControlId::ZoomAbs, &[0xf4, 0x01]);
+    respond(
+        &mock,
+        Request::GetCur,
+        ControlId::DigitalMultiplier,
+        &[0xc8, 0x00],
+    );
+    assert_eq!(device.zoom_effective().unwrap(), 600);
+
+    respond(&mock, Request::GetCur, ControlId::ZoomAbs, &[0x2c, 0x01]);
+    assert_eq!(device.zoom_effective().unwrap(), 300);
+
+    mock.clear_transfers();
+    device.zoom_effective_set(600).unwrap();
+    assert_eq!(
+        zoom_sets(&mock),
+        [
+            transfer(Request::SetCur, ControlId::ZoomAbs, &[0xf4, 0x01]),
+            transfer(Request::SetCur, ControlId::DigitalMultiplier, &[0xc8, 0x00]),
+        ]
+    );
+
+    mock.clear_transfers();
+    device.zoom_effective_set(900).unwrap();
+    assert_eq!(
+        zoom_sets(&mock),
+        [
+            transfer(Request::SetCur, ControlId::ZoomAbs, &[0xf4, 0x01]),
+            transfer(Request::SetCur, ControlId::DigitalMultiplier, &[0xfa, 0x00]),
+        ]
+    );
+}
+
+#[test]
+fn zoom_effective_clamps_below_optical_min() {
+    let (mock, device) = zoom_device(Some(&[0xfa, 0x00]));
+
+    device.zoom_effective_set(20).unwrap();
+    assert_eq!(
+        zoom_sets(&mock),
+        [
+            transfer(Request::SetCur, ControlId::DigitalMultiplier, &[0x64, 0x00]),
+            transfer(Request::SetCur, ControlId::ZoomAbs, &[0x64, 0x00]),
+        ]
+    );
+}
+
+#[test]
+fn zoom_effective_without_multiplier_limit() {
+    let (_, device) = zoom_device(None);
+    assert_caps(device.zoom_effective_caps().unwrap(), 100, 800, 1, 100);
+}
+
+#[test]
+fn applied_skips_relative_controls() {
+    let (mock, device) = device();
+    respond(&mock, Request::GetCur, ControlId::PanTiltRel, &[0x00; 4]);
+
+    device.zoom_abs_set(200).unwrap();
+    device.pan_rel_move(1, 4).unwrap();
+    device.zoom_rel_move(-1, 1).unwrap();
+    device.focus_rel_set(1, 1).unwrap();
+    device.iris_rel_set(1).unwrap();
+    device.exposure_time_rel_set(1).unwrap();
+    device.brightness_set(5).unwrap();
+    device.zoom_abs_set(300).unwrap();
+
+    assert_eq!(
+        device.applied(),
+        [
+            (ControlId::Brightness, 5.into()),
+            (ControlId::ZoomAbs, 300.into()),
+        ]
+    );
+}
+
+#[test]
+fn replay_keeps_rejected_values() {
+    let (mock, device) = device();
+    mock.fail(
+        Request::SetCur,
+        ControlId::Gain.selector(),
+        PU,
+        TransferError::Stall,
+    );
+    let applied = vec![
+        (ControlId::Gain, 4.into()),
+        (ControlId::Brightness, 5.into()),
+    ];
+
+    let errors = device.replay(applied.clone());
+    assert!(matches!(
+        errors[..],
+        [(ControlId::Gain, Error::UbsTransfer(TransferError::Stall))]
+    ));
+    assert_eq!(
+        mock.transfers()
+            .into_iter()
+            .filter(|t| t.request == Request::SetCur as u8)
+            .count(),
+        2
+    );
+    assert_eq!(device.applied(), applied);
+}
@@ -0,0 +1,69 @@
+use super::{DeviceInfo, Stopper};
+use crate::Error;
+use futures_lite::StreamExt;
+use nusb::hotplug::{HotplugEvent, HotplugWatch};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub enum DeviceEvent {
+    Arrived(DeviceInfo),
+    /// Carries the info the device had when it arrived.
+    Left(DeviceInfo),
+}
+
+/// Blocks until a video-class device is connected or disconnected.
+///
+/// Devices already present when the watcher is created are not reported as arrived.
+pub struct DeviceWatcher {
+    watch: HotplugWatch,
+    devices: HashMap<nusb::DeviceId, DeviceInfo>,
+    stopper: Stopper,
+}
+
+impl DeviceWatcher {
+    pub fn new() -> Result<Self, Error> {
+        // Watch before listing so that nothing connected in between is missed.
+        let watch = nusb::watch_devices()?;
+        let devices = DeviceInfo::enumerate()?
+            .into_iter()
+            .map(|info| (info.inner.id(), info))
+            .collect();
+
+        Ok(DeviceWatcher {
+            watch,
+            devices,
+            stopper: Stopper::default(),
+        })
+    }
+
+    pub fn stopper(&self) -> Stopper {
+        self.stopper.clone()
+    }
+}
+
+impl Iterator for DeviceWatcher {
+    type Item = DeviceEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let watch = &mut self.watch;
+            let event = self.stopper.block_on(|cx| watch.poll_next(cx))??;
+
+            match event {
+                HotplugEvent::Connected(inner) => {
+                    if !DeviceInfo::is_video(&inner) {
+                        continue;
+                    }
+                    let info = DeviceInfo { inner };
+                    self.devices.insert(info.inner.id(), info.clone());
+                    return Some(DeviceEvent::Arrived(info));
+                }
+                HotplugEvent::Disconnected(id) => {
+                    if let Some(info) = self.devices.remove(&id) {
+                        return Some(DeviceEvent::Left(info));
+                    }
+                }
+            }
+        }
+    }
+}
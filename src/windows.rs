@@ -1,4 +1,4 @@
-use crate::{Caps, Error};
+use crate::{control::EffectiveZoom, Caps, Error, PowerLineFrequency};
 use std::os::windows::io::AsRawHandle;
 use std::{fs, mem, ptr};
 use windows::core::{Interface, GUID, HSTRING, PWSTR};
@@ -117,12 +117,14 @@ impl DeviceInfo {
         let num_nodes = unsafe { topology_info.NumNodes() }?;
         let ks_control: KernelStreaming::IKsControl = source.cast()?;
         let am_control: DirectShow::IAMCameraControl = source.cast()?;
+        let vp_control: DirectShow::IAMVideoProcAmp = source.cast()?;
         let file = fs::File::open(self.symbolic_link())?;
 
         Ok(Device {
             num_nodes,
             ks_control,
             am_control,
+            vp_control,
             file,
         })
     }
@@ -132,6 +134,7 @@ pub struct Device {
     num_nodes: u32,
     ks_control: KernelStreaming::IKsControl,
     am_control: DirectShow::IAMCameraControl,
+    vp_control: DirectShow::IAMVideoProcAmp,
     file: fs::File,
 }
 
@@ -176,6 +179,67 @@ impl Device {
         }?)
     }
 
+    fn auto(&self, control_code: i32) -> Result<bool, Error> {
+        let mut cur = 0;
+        let mut flags = 0;
+        unsafe { self.am_control.Get(control_code, &mut cur, &mut flags) }?;
+        Ok(flags & DirectShow::CameraControl_Flags_Auto.0 != 0)
+    }
+
+    fn auto_set(&self, control_code: i32, value: bool) -> Result<(), Error> {
+        let cur = self.get(control_code)?;
+        let flags = if value {
+            DirectShow::CameraControl_Flags_Auto
+        } else {
+            DirectShow::CameraControl_Flags_Manual
+        };
+        Ok(unsafe { self.am_control.Set(control_code, cur, flags.0) }?)
+    }
+
+    pub fn vp_caps(&self, property: i32) -> Result<Caps, Error> {
+        let mut min = 0;
+        let mut max = 0;
+        let mut res = 0;
+        let mut def = 0;
+        let mut flags = 0;
+        unsafe {
+            self.vp_control
+                .GetRange(property, &mut min, &mut max, &mut res, &mut def, &mut flags)
+        }?;
+        Ok(Caps { min, max, res, def })
+    }
+
+    pub fn vp_get(&self, property: i32) -> Result<i32, Error> {
+        let mut cur = 0;
+        let mut flags = 0;
+        unsafe { self.vp_control.Get(property, &mut cur, &mut flags) }?;
+        Ok(cur)
+    }
+
+    pub fn vp_set(&self, property: i32, value: i32) -> Result<(), Error> {
+        Ok(unsafe {
+            self.vp_control
+                .Set(property, value, DirectShow::VideoProcAmp_Flags_Manual.0)
+        }?)
+    }
+
+    fn vp_auto(&self, property: i32) -> Result<bool, Error> {
+        let mut cur = 0;
+        let mut flags = 0;
+        unsafe { self.vp_control.Get(property, &mut cur, &mut flags) }?;
+        Ok(flags & DirectShow::VideoProcAmp_Flags_Auto.0 != 0)
+    }
+
+    fn vp_auto_set(&self, property: i32, value: bool) -> Result<(), Error> {
+        let cur = self.vp_get(property)?;
+        let flags = if value {
+            DirectShow::VideoProcAmp_Flags_Auto
+        } else {
+            DirectShow::VideoProcAmp_Flags_Manual
+        };
+        Ok(unsafe { self.vp_control.Set(property, cur, flags.0) }?)
+    }
+
     pub fn usb_descriptor(&self) -> Result<Vec<u8>, Error> {
         let req = Usb::USBSCAN_GET_DESCRIPTOR {
             DescriptorType: Usb::USB_CONFIGURATION_DESCRIPTOR_TYPE as _,
@@ -325,10 +389,21 @@ impl Device {
         self.get(KernelStreaming::KSPROPERTY_CAMERACONTROL_ZOOM_RELATIVE.0)
     }
 
+    /// Moves in the direction of `value` at the slowest speed.
     pub fn zoom_rel_set(&self, value: i32) -> Result<(), Error> {
+        let speed_caps = self.zoom_rel_speed_caps()?;
+        self.zoom_rel_move(value, speed_caps.min)
+    }
+
+    /// On Windows the relative property takes a signed speed, so its range is the speed range.
+    pub fn zoom_rel_speed_caps(&self) -> Result<Caps, Error> {
+        self.zoom_rel_caps()
+    }
+
+    pub fn zoom_rel_move(&self, direction: i32, speed: i32) -> Result<(), Error> {
         self.set(
             KernelStreaming::KSPROPERTY_CAMERACONTROL_ZOOM_RELATIVE.0,
-            value,
+            direction.signum() * speed,
         )
     }
 
@@ -352,10 +427,21 @@ impl Device {
         self.get(KernelStreaming::KSPROPERTY_CAMERACONTROL_PAN_RELATIVE.0)
     }
 
+    /// Moves in the direction of `value` at the slowest speed.
     pub fn pan_rel_set(&self, value: i32) -> Result<(), Error> {
+        let speed_caps = self.pan_rel_speed_caps()?;
+        self.pan_rel_move(value, speed_caps.min)
+    }
+
+    /// On Windows the relative property takes a signed speed, so its range is the speed range.
+    pub fn pan_rel_speed_caps(&self) -> Result<Caps, Error> {
+        self.pan_rel_caps()
+    }
+
+    pub fn pan_rel_move(&self, direction: i32, speed: i32) -> Result<(), Error> {
         self.set(
             KernelStreaming::KSPROPERTY_CAMERACONTROL_PAN_RELATIVE.0,
-            value,
+            direction.signum() * speed,
         )
     }
 
@@ -379,12 +465,338 @@ impl Device {
         self.get(KernelStreaming::KSPROPERTY_CAMERACONTROL_TILT_RELATIVE.0)
     }
 
+    /// Moves in the direction of `value` at the slowest speed.
     pub fn tilt_rel_set(&self, value: i32) -> Result<(), Error> {
+        let speed_caps = self.tilt_rel_speed_caps()?;
+        self.tilt_rel_move(value, speed_caps.min)
+    }
+
+    /// On Windows the relative property takes a signed speed, so its range is the speed range.
+    pub fn tilt_rel_speed_caps(&self) -> Result<Caps, Error> {
+        self.tilt_rel_caps()
+    }
+
+    pub fn tilt_rel_move(&self, direction: i32, speed: i32) -> Result<(), Error> {
         self.set(
             KernelStreaming::KSPROPERTY_CAMERACONTROL_TILT_RELATIVE.0,
+            direction.signum() * speed,
+        )
+    }
+
+    fn pan_tilt(
+        &self,
+        id: KernelStreaming::KSPROPERTY_VIDCAP_CAMERACONTROL,
+    ) -> Result<(i32, i32), Error> {
+        let mut property = KernelStreaming::KSPROPERTY_CAMERACONTROL_S2::default();
+        property.Property.Anonymous.Anonymous.Set = KernelStreaming::PROPSETID_VIDCAP_CAMERACONTROL;
+        property.Property.Anonymous.Anonymous.Id = id.0 as _;
+        property.Property.Anonymous.Anonymous.Flags = KernelStreaming::KSPROPERTY_TYPE_GET;
+
+        let mut data = property;
+        let mut r = 0;
+        unsafe {
+            self.ks_control.KsProperty(
+                &property.Property,
+                mem::size_of_val(&property) as _,
+                &mut data as *mut _ as _,
+                mem::size_of_val(&data) as _,
+                &mut r,
+            )
+        }?;
+        Ok((data.Value1, data.Value2))
+    }
+
+    fn pan_tilt_set(
+        &self,
+        id: KernelStreaming::KSPROPERTY_VIDCAP_CAMERACONTROL,
+        flags: u32,
+        value1: i32,
+        value2: i32,
+    ) -> Result<(), Error> {
+        let mut property = KernelStreaming::KSPROPERTY_CAMERACONTROL_S2::default();
+        property.Property.Anonymous.Anonymous.Set = KernelStreaming::PROPSETID_VIDCAP_CAMERACONTROL;
+        property.Property.Anonymous.Anonymous.Id = id.0 as _;
+        property.Property.Anonymous.Anonymous.Flags = KernelStreaming::KSPROPERTY_TYPE_SET;
+        property.Value1 = value1;
+        property.Value2 = value2;
+        property.Flags = flags;
+
+        let mut data = property;
+        let mut r = 0;
+        unsafe {
+            self.ks_control.KsProperty(
+                &property.Property,
+                mem::size_of_val(&property) as _,
+                &mut data as *mut _ as _,
+                mem::size_of_val(&data) as _,
+                &mut r,
+            )
+        }?;
+        Ok(())
+    }
+
+    pub fn pan_tilt_abs(&self) -> Result<(i32, i32), Error> {
+        self.pan_tilt(KernelStreaming::KSPROPERTY_CAMERACONTROL_PANTILT)
+    }
+
+    pub fn pan_tilt_abs_set(&self, pan: i32, tilt: i32) -> Result<(), Error> {
+        self.pan_tilt_set(
+            KernelStreaming::KSPROPERTY_CAMERACONTROL_PANTILT,
+            KernelStreaming::KSPROPERTY_CAMERACONTROL_FLAGS_MANUAL as _,
+            pan,
+            tilt,
+        )
+    }
+
+    pub fn pan_tilt_rel_set(
+        &self,
+        pan_direction: i32,
+        pan_speed: i32,
+        tilt_direction: i32,
+        tilt_speed: i32,
+    ) -> Result<(), Error> {
+        self.pan_tilt_set(
+            KernelStreaming::KSPROPERTY_CAMERACONTROL_PANTILT_RELATIVE,
+            (KernelStreaming::KSPROPERTY_CAMERACONTROL_FLAGS_MANUAL
+                | KernelStreaming::KSPROPERTY_CAMERACONTROL_FLAGS_RELATIVE) as _,
+            pan_direction.signum() * pan_speed,
+            tilt_direction.signum() * tilt_speed,
+        )
+    }
+
+    pub fn focus_abs_caps(&self) -> Result<Caps, Error> {
+        self.caps(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS.0)
+    }
+
+    pub fn focus_abs(&self) -> Result<i32, Error> {
+        self.get(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS.0)
+    }
+
+    pub fn focus_abs_set(&self, value: i32) -> Result<(), Error> {
+        self.set(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS.0, value)
+    }
+
+    pub fn focus_rel_speed_caps(&self) -> Result<Caps, Error> {
+        self.caps(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS_RELATIVE.0)
+    }
+
+    pub fn focus_rel(&self) -> Result<i32, Error> {
+        self.get(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS_RELATIVE.0)
+    }
+
+    pub fn focus_rel_set(&self, direction: i32, speed: i32) -> Result<(), Error> {
+        self.set(
+            KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS_RELATIVE.0,
+            direction.signum() * speed,
+        )
+    }
+
+    pub fn focus_auto(&self) -> Result<bool, Error> {
+        self.auto(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS.0)
+    }
+
+    pub fn focus_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.auto_set(KernelStreaming::KSPROPERTY_CAMERACONTROL_FOCUS.0, value)
+    }
+
+    pub fn privacy(&self) -> Result<bool, Error> {
+        Ok(self.get(KernelStreaming::KSPROPERTY_CAMERACONTROL_PRIVACY.0)? != 0)
+    }
+
+    pub fn privacy_set(&self, value: bool) -> Result<(), Error> {
+        self.set(
+            KernelStreaming::KSPROPERTY_CAMERACONTROL_PRIVACY.0,
+            value as _,
+        )
+    }
+
+    pub fn brightness_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_BRIGHTNESS.0)
+    }
+
+    pub fn brightness(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_BRIGHTNESS.0)
+    }
+
+    pub fn brightness_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_BRIGHTNESS.0, value)
+    }
+
+    pub fn contrast_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_CONTRAST.0)
+    }
+
+    pub fn contrast(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_CONTRAST.0)
+    }
+
+    pub fn contrast_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_CONTRAST.0, value)
+    }
+
+    pub fn hue_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_HUE.0)
+    }
+
+    pub fn hue(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_HUE.0)
+    }
+
+    pub fn hue_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_HUE.0, value)
+    }
+
+    pub fn saturation_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_SATURATION.0)
+    }
+
+    pub fn saturation(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_SATURATION.0)
+    }
+
+    pub fn saturation_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_SATURATION.0, value)
+    }
+
+    pub fn sharpness_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_SHARPNESS.0)
+    }
+
+    pub fn sharpness(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_SHARPNESS.0)
+    }
+
+    pub fn sharpness_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_SHARPNESS.0, value)
+    }
+
+    pub fn gamma_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_GAMMA.0)
+    }
+
+    pub fn gamma(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_GAMMA.0)
+    }
+
+    pub fn gamma_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_GAMMA.0, value)
+    }
+
+    pub fn gain_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_GAIN.0)
+    }
+
+    pub fn gain(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_GAIN.0)
+    }
+
+    pub fn gain_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_GAIN.0, value)
+    }
+
+    pub fn hue_auto(&self) -> Result<bool, Error> {
+        self.vp_auto(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_HUE.0)
+    }
+
+    pub fn hue_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.vp_auto_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_HUE.0, value)
+    }
+
+    pub fn contrast_auto(&self) -> Result<bool, Error> {
+        self.vp_auto(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_CONTRAST.0)
+    }
+
+    pub fn contrast_auto_set(&self, value: bool) -> Result<(), Error> {
+        self.vp_auto_set(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_CONTRAST.0, value)
+    }
+
+    pub fn power_line_frequency(&self) -> Result<PowerLineFrequency, Error> {
+        let cur = self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_POWERLINE_FREQUENCY.0)?;
+        PowerLineFrequency::from_u8(cur as _).ok_or(Error::InvalidValue)
+    }
+
+    pub fn power_line_frequency_set(&self, value: PowerLineFrequency) -> Result<(), Error> {
+        let property = KernelStreaming::KSPROPERTY_VIDEOPROCAMP_POWERLINE_FREQUENCY.0;
+        if !self.vp_caps(property)?.contains(value as _) {
+            return Err(Error::InvalidValue);
+        }
+        self.vp_set(property, value as _)
+    }
+
+    pub fn backlight_compensation_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_BACKLIGHT_COMPENSATION.0)
+    }
+
+    pub fn backlight_compensation(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_BACKLIGHT_COMPENSATION.0)
+    }
+
+    pub fn backlight_compensation_set(&self, value: i32) -> Result<(), Error> {
+        if !self.backlight_compensation_caps()?.contains(value) {
+            return Err(Error::InvalidValue);
+        }
+        self.vp_set(
+            KernelStreaming::KSPROPERTY_VIDEOPROCAMP_BACKLIGHT_COMPENSATION.0,
             value,
         )
     }
+
+    pub fn digital_multiplier_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_DIGITAL_MULTIPLIER.0)
+    }
+
+    pub fn digital_multiplier(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_DIGITAL_MULTIPLIER.0)
+    }
+
+    pub fn digital_multiplier_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(
+            KernelStreaming::KSPROPERTY_VIDEOPROCAMP_DIGITAL_MULTIPLIER.0,
+            value,
+        )
+    }
+
+    pub fn digital_multiplier_limit_caps(&self) -> Result<Caps, Error> {
+        self.vp_caps(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_DIGITAL_MULTIPLIER_LIMIT.0)
+    }
+
+    pub fn digital_multiplier_limit(&self) -> Result<i32, Error> {
+        self.vp_get(KernelStreaming::KSPROPERTY_VIDEOPROCAMP_DIGITAL_MULTIPLIER_LIMIT.0)
+    }
+
+    pub fn digital_multiplier_limit_set(&self, value: i32) -> Result<(), Error> {
+        self.vp_set(
+            KernelStreaming::KSPROPERTY_VIDEOPROCAMP_DIGITAL_MULTIPLIER_LIMIT.0,
+            value,
+        )
+    }
+
+    /// Optical zoom followed by the digital multiplier up to its current limit, as a single
+    /// range.
+    pub fn zoom_effective_caps(&self) -> Result<Caps, Error> {
+        Ok(self.effective_zoom()?.caps())
+    }
+
+    pub fn zoom_effective(&self) -> Result<i32, Error> {
+        let zoom = self.effective_zoom()?;
+        Ok(zoom.value(self.zoom_abs()?, self.digital_multiplier()?))
+    }
+
+    pub fn zoom_effective_set(&self, value: i32) -> Result<(), Error> {
+        self.effective_zoom()?.set(
+            value,
+            |v| self.zoom_abs_set(v),
+            |v| self.digital_multiplier_set(v),
+        )
+    }
+
+    fn effective_zoom(&self) -> Result<EffectiveZoom, Error> {
+        Ok(EffectiveZoom {
+            optical: self.zoom_abs_caps()?,
+            digital: self.digital_multiplier_caps()?,
+            limit: self.digital_multiplier_limit().ok(),
+        })
+    }
 }
 
 struct NodeInfo {